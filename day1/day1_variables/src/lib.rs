@@ -1,3 +1,14 @@
+mod scenario;
+mod shadow;
+
+pub use scenario::{
+    OwnershipRule, Scenario, ScenarioCheck, ScenarioOutcome, ScenarioReport, ScenarioResult,
+    run_scenarios, scenarios,
+};
+pub use shadow::{
+    JsonRenderer, MarkdownRenderer, PlainTextRenderer, ShadowStep, ShadowTrace, TraceRenderer,
+};
+
 // - `describe_mutability()` 함수는 불변 변수와 가변 변수의 차이를 설명하는 문자열을 반환합니다. 문자열 안에는 예시 코드 조각을 포함해야 합니다.
 // - `shadow_example()` 함수는 같은 이름의 변수를 섀도잉하여 타입이 바뀌는 과정을 보여 주는 벡터를 반환합니다. 벡터에는 각 단계의 값이 담겨 있어야 합니다.
 // - `move_semantics_demo()` 함수는 소유권이 이동한 이후 원래 변수를 사용할 수 없는 상황을 문자열 설명과 함께 `Result<(), String>` 형태로 표현해야 합니다. `Ok(())`를 반환하는 대신 실패 메시지를 `Err`로 반환하고, 테스트에서 그 메시지를 검증하세요.
@@ -19,6 +30,7 @@ pub fn describe_mutability() -> String {
 }
 
 // 같은 이름의 변수를 섀도잉하여 타입이 바뀌는 과정을 보여 주는 벡터를 반환
+// 같은 과정을 구조화된 기록으로 받거나 다른 형식으로 바꾸려면 shadow_trace()와 TraceRenderer를 쓰세요.
 pub fn shadow_example() -> Vec<String> {
     let mut result = Vec::new();
 
@@ -59,8 +71,28 @@ pub fn shadow_example() -> Vec<String> {
     return result;
 }
 
+// shadow_example의 섀도잉 과정을 문자열 대신 구조화된 기록으로 돌려줍니다.
+// 교재는 PlainTextRenderer나 MarkdownRenderer로, 테스트는 steps()의 필드로 같은 기록을 확인합니다.
+pub fn shadow_trace() -> ShadowTrace {
+    let mut trace = ShadowTrace::new();
+
+    // 불변 변수 (섀도잉 가능)
+    crate::shadow_step!(trace, let immutable_value = "gyuseon Min");
+    crate::shadow_step!(trace, let immutable_value = immutable_value.to_string());
+    crate::shadow_step!(trace, let immutable_value = immutable_value.len());
+
+    // 가변 변수 (재대입과 섀도잉 모두 가능)
+    crate::shadow_step!(trace, let mut mutable_value = "gyuseon Min");
+    crate::shadow_step!(trace, mutable_value = "gyuseon");
+    crate::shadow_step!(trace, let mutable_value = mutable_value.to_string());
+    crate::shadow_step!(trace, let mutable_value = mutable_value.len());
+
+    trace
+}
+
 // 소유권이 이동한 이후 원래 변수를 사용할 수 없는 상황을 문자열 설명과 함께 `Result<(), String>` 형태로 표현해야 합니다. 
 // `Ok(())`를 반환하는 대신 실패 메시지를 `Err`로 반환하고, 테스트에서 그 메시지를 검증하세요.
+// 이 설명을 실제 코드로 확인하는 시나리오는 scenarios()와 run_scenarios()에 있습니다.
 pub fn move_semantics_demo() -> Result<(), String> {
     let before = String::from("gyuseon");
     let after = before; // 소유권이 before에서 after로 이동
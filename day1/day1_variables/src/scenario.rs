@@ -0,0 +1,213 @@
+use std::fmt;
+
+// 소유권 시나리오가 다루는 규칙
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OwnershipRule {
+    Move,
+    Clone,
+    Borrow,
+    Copy,
+}
+
+// 시나리오를 어떻게 확인하는지.
+// 허용되는 코드는 실제로 실행하고, 허용되지 않는 코드는 tests/ui의 파일이 컴파일되지 않는지를 trybuild로 확인합니다.
+#[derive(Debug, Clone, Copy)]
+pub enum ScenarioCheck {
+    Runs(fn() -> Result<(), String>),
+    // 크레이트 루트 기준 경로
+    FailsToCompile { case: &'static str },
+}
+
+// 소유권 수업 한 토막: 어떤 규칙을, 어떤 코드로, 무엇을 보여 주는지
+#[derive(Debug, Clone, Copy)]
+pub struct Scenario {
+    pub name: &'static str,
+    pub rule: OwnershipRule,
+    pub lesson: &'static str,
+    pub check: ScenarioCheck,
+}
+
+// 문자열 설명 대신 코드로 증명하는 소유권 시나리오 목록입니다.
+// 새 시나리오는 여기에 추가하고, 컴파일 실패 시나리오라면 tests/ui에 파일과 .stderr를 함께 둡니다.
+pub fn scenarios() -> Vec<Scenario> {
+    vec![
+        Scenario {
+            name: "move_transfers_ownership",
+            rule: OwnershipRule::Move,
+            lesson: "String을 다른 변수에 대입하면 소유권(ownership)이 이동하고 새 변수만 값을 씁니다.",
+            check: ScenarioCheck::Runs(move_transfers_ownership),
+        },
+        Scenario {
+            name: "use_after_move",
+            rule: OwnershipRule::Move,
+            lesson: "소유권이 이동한 뒤 원래 변수를 쓰면 컴파일되지 않습니다.",
+            check: ScenarioCheck::FailsToCompile {
+                case: "tests/ui/use_after_move.rs",
+            },
+        },
+        Scenario {
+            name: "clone_keeps_both",
+            rule: OwnershipRule::Clone,
+            lesson: "clone()은 힙 데이터까지 복제하므로 두 변수 모두 쓸 수 있습니다.",
+            check: ScenarioCheck::Runs(clone_keeps_both),
+        },
+        Scenario {
+            name: "clone_without_clone",
+            rule: OwnershipRule::Clone,
+            lesson: "Clone을 구현하지 않은 타입은 clone()을 부를 수 없습니다.",
+            check: ScenarioCheck::FailsToCompile {
+                case: "tests/ui/clone_without_clone.rs",
+            },
+        },
+        Scenario {
+            name: "borrows_leave_owner_usable",
+            rule: OwnershipRule::Borrow,
+            lesson: "&와 &mut로 빌려도 소유권은 그대로라서, 빌림이 끝나면 원래 변수를 계속 씁니다.",
+            check: ScenarioCheck::Runs(borrows_leave_owner_usable),
+        },
+        Scenario {
+            name: "mutable_borrow_while_shared",
+            rule: OwnershipRule::Borrow,
+            lesson: "불변 참조가 살아 있는 동안에는 가변 참조를 만들 수 없습니다.",
+            check: ScenarioCheck::FailsToCompile {
+                case: "tests/ui/mutable_borrow_while_shared.rs",
+            },
+        },
+        Scenario {
+            name: "copy_types_duplicate",
+            rule: OwnershipRule::Copy,
+            lesson: "정수 같은 Copy 타입은 대입할 때 값이 복사되어 원래 변수도 그대로 남습니다.",
+            check: ScenarioCheck::Runs(copy_types_duplicate),
+        },
+        Scenario {
+            name: "copy_with_heap_field",
+            rule: OwnershipRule::Copy,
+            lesson: "String 같은 힙 데이터를 가진 타입은 Copy가 될 수 없습니다.",
+            check: ScenarioCheck::FailsToCompile {
+                case: "tests/ui/copy_with_heap_field.rs",
+            },
+        },
+    ]
+}
+
+fn move_transfers_ownership() -> Result<(), String> {
+    let before = String::from("gyuseon");
+    let after = before;
+    expect(
+        after == "gyuseon",
+        "이동한 값은 새 변수에서 그대로 읽혀야 합니다",
+    )
+}
+
+fn clone_keeps_both() -> Result<(), String> {
+    let original = String::from("gyuseon");
+    let mut copy = original.clone();
+    copy.push_str(" Min");
+    expect(
+        original == "gyuseon" && copy == "gyuseon Min",
+        "복제본을 바꿔도 원본은 바뀌지 않아야 합니다",
+    )
+}
+
+fn borrows_leave_owner_usable() -> Result<(), String> {
+    let mut blocks = vec![100u64, 101];
+    let (first, len) = (&blocks[0], blocks.len());
+    expect(
+        *first == 100 && len == 2,
+        "불변 참조로 읽을 수 있어야 합니다",
+    )?;
+    {
+        let writer = &mut blocks;
+        writer.push(102);
+    }
+    expect(
+        blocks == [100, 101, 102],
+        "가변 빌림이 끝나면 소유자가 바뀐 값을 읽어야 합니다",
+    )
+}
+
+fn copy_types_duplicate() -> Result<(), String> {
+    let slot: u64 = 42;
+    let mut copied = slot;
+    copied += 1;
+    expect(
+        slot == 42 && copied == 43,
+        "Copy 타입은 대입 후에도 원래 값이 남아야 합니다",
+    )
+}
+
+fn expect(condition: bool, message: &str) -> Result<(), String> {
+    if condition {
+        Ok(())
+    } else {
+        Err(message.to_string())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScenarioOutcome {
+    Passed,
+    Failed(String),
+    // 실행으로는 확인할 수 없고, trybuild 테스트가 컴파일 실패를 확인합니다.
+    CompileFailCase(&'static str),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScenarioResult {
+    pub name: &'static str,
+    pub rule: OwnershipRule,
+    pub lesson: &'static str,
+    pub outcome: ScenarioOutcome,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScenarioReport {
+    pub results: Vec<ScenarioResult>,
+}
+
+impl ScenarioReport {
+    // 실행한 시나리오가 모두 통과했는지 (컴파일 실패 시나리오는 trybuild 테스트 몫입니다)
+    pub fn all_passed(&self) -> bool {
+        self.results
+            .iter()
+            .all(|result| !matches!(result.outcome, ScenarioOutcome::Failed(_)))
+    }
+}
+
+impl fmt::Display for ScenarioReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for result in &self.results {
+            let status = match &result.outcome {
+                ScenarioOutcome::Passed => "통과".to_string(),
+                ScenarioOutcome::Failed(reason) => format!("실패: {reason}"),
+                ScenarioOutcome::CompileFailCase(case) => format!("컴파일 실패로 확인 ({case})"),
+            };
+            writeln!(
+                f,
+                "[{:?}] {} - {} => {}",
+                result.rule, result.name, result.lesson, status
+            )?;
+        }
+        Ok(())
+    }
+}
+
+// 허용되는 시나리오는 실제로 실행하고, 결과를 시나리오 순서대로 모읍니다.
+pub fn run_scenarios(scenarios: &[Scenario]) -> ScenarioReport {
+    let results = scenarios
+        .iter()
+        .map(|scenario| ScenarioResult {
+            name: scenario.name,
+            rule: scenario.rule,
+            lesson: scenario.lesson,
+            outcome: match scenario.check {
+                ScenarioCheck::Runs(run) => match run() {
+                    Ok(()) => ScenarioOutcome::Passed,
+                    Err(reason) => ScenarioOutcome::Failed(reason),
+                },
+                ScenarioCheck::FailsToCompile { case } => ScenarioOutcome::CompileFailCase(case),
+            },
+        })
+        .collect();
+    ScenarioReport { results }
+}
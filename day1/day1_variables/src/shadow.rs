@@ -0,0 +1,128 @@
+use std::fmt::{self, Write};
+
+// 섀도잉(또는 재대입) 한 단계의 기록입니다. 값과 타입을 문자열 안에 섞지 않고 따로 들고 있어서
+// 교재(텍스트, 마크다운)와 테스트(JSON, 필드 비교)가 같은 기록을 씁니다.
+// One recorded (re)binding: which name, its value and type, and whether it was `let mut`.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ShadowStep {
+    pub step: usize,
+    pub binding: String,
+    pub value: String,
+    pub type_name: String,
+    pub mutable: bool,
+}
+
+// 단계들을 순서대로 모읍니다. 보통 shadow_step! 매크로가 record를 대신 불러 줍니다.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ShadowTrace {
+    steps: Vec<ShadowStep>,
+}
+
+impl ShadowTrace {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // 값은 Display로, 타입은 type_name으로 기록합니다. 단계 번호는 1부터 붙습니다.
+    pub fn record<T: fmt::Display + ?Sized>(&mut self, binding: &str, value: &T, mutable: bool) {
+        self.steps.push(ShadowStep {
+            step: self.steps.len() + 1,
+            binding: binding.to_string(),
+            value: value.to_string(),
+            type_name: std::any::type_name::<T>().to_string(),
+            mutable,
+        });
+    }
+
+    pub fn steps(&self) -> &[ShadowStep] {
+        &self.steps
+    }
+
+    pub fn render(&self, renderer: &impl TraceRenderer) -> String {
+        renderer.render(&self.steps)
+    }
+}
+
+// 바인딩을 만들면서 곧바로 trace에 기록합니다. 세 가지 형태를 받습니다.
+//   shadow_step!(trace, let name = value);      불변 바인딩 (섀도잉 포함)
+//   shadow_step!(trace, let mut name = value);  가변 바인딩
+//   shadow_step!(trace, name = value);          가변 변수에 재대입
+// 매크로가 만든 let은 호출한 쪽 스코프에 그대로 남으므로, 이후 코드에서 name을 평소처럼 씁니다.
+#[macro_export]
+macro_rules! shadow_step {
+    ($trace:expr, let mut $name:ident = $value:expr) => {
+        let mut $name = $value;
+        $trace.record(stringify!($name), &$name, true);
+    };
+    ($trace:expr, let $name:ident = $value:expr) => {
+        let $name = $value;
+        $trace.record(stringify!($name), &$name, false);
+    };
+    ($trace:expr, $name:ident = $value:expr) => {
+        $name = $value;
+        $trace.record(stringify!($name), &$name, true);
+    };
+}
+
+// 같은 기록을 여러 형식으로 바꿉니다. 새 형식이 필요하면 이 트레이트를 구현합니다.
+pub trait TraceRenderer {
+    fn render(&self, steps: &[ShadowStep]) -> String;
+}
+
+// 한 줄에 한 단계. 예: "1. 불변 변수 name: 값: gyuseon Min, 타입: &str"
+pub struct PlainTextRenderer;
+
+impl TraceRenderer for PlainTextRenderer {
+    fn render(&self, steps: &[ShadowStep]) -> String {
+        let mut out = String::new();
+        for step in steps {
+            writeln!(
+                out,
+                "{}. {} 변수 {}: 값: {}, 타입: {}",
+                step.step,
+                if step.mutable { "가변" } else { "불변" },
+                step.binding,
+                step.value,
+                step.type_name
+            )
+            .unwrap();
+        }
+        out
+    }
+}
+
+// 단계 배열을 그대로 담은 JSON
+pub struct JsonRenderer;
+
+impl TraceRenderer for JsonRenderer {
+    fn render(&self, steps: &[ShadowStep]) -> String {
+        serde_json::to_string_pretty(steps).expect("ShadowStep always serializes")
+    }
+}
+
+// 교재에 바로 붙일 수 있는 마크다운 표
+pub struct MarkdownRenderer;
+
+impl TraceRenderer for MarkdownRenderer {
+    fn render(&self, steps: &[ShadowStep]) -> String {
+        let mut out = String::from("| 단계 | 바인딩 | 값 | 타입 | mut |\n|---|---|---|---|---|\n");
+        for step in steps {
+            writeln!(
+                out,
+                "| {} | `{}` | {} | `{}` | {} |",
+                step.step,
+                step.binding,
+                markdown_cell(&step.value),
+                step.type_name,
+                if step.mutable { "yes" } else { "no" }
+            )
+            .unwrap();
+        }
+        out
+    }
+}
+
+// 표를 깨뜨리는 세로줄과 줄바꿈만 바꿔 씁니다.
+fn markdown_cell(value: &str) -> String {
+    value.replace('|', "\\|").replace('\n', "<br>")
+}
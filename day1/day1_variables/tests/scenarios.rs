@@ -0,0 +1,54 @@
+use day1_variables::{OwnershipRule, ScenarioCheck, ScenarioOutcome, run_scenarios, scenarios};
+
+#[test]
+fn test_allowed_scenarios_run() {
+    let report = run_scenarios(&scenarios());
+    println!("{report}");
+
+    // 실행 가능한 시나리오는 모두 실제 코드로 통과해야 함
+    assert!(report.all_passed(), "실패한 시나리오가 있습니다:\n{report}");
+    let passed = report
+        .results
+        .iter()
+        .filter(|result| result.outcome == ScenarioOutcome::Passed)
+        .count();
+    assert_eq!(passed, 4);
+}
+
+#[test]
+fn test_every_rule_has_allowed_and_disallowed_case() {
+    // move, clone, borrow, Copy 규칙마다 되는 코드와 안 되는 코드가 하나씩 있어야 함
+    let scenarios = scenarios();
+    for rule in [
+        OwnershipRule::Move,
+        OwnershipRule::Clone,
+        OwnershipRule::Borrow,
+        OwnershipRule::Copy,
+    ] {
+        let of_rule: Vec<_> = scenarios
+            .iter()
+            .filter(|scenario| scenario.rule == rule)
+            .collect();
+        assert!(
+            of_rule
+                .iter()
+                .any(|scenario| matches!(scenario.check, ScenarioCheck::Runs(_)))
+        );
+        assert!(
+            of_rule
+                .iter()
+                .any(|scenario| matches!(scenario.check, ScenarioCheck::FailsToCompile { .. }))
+        );
+    }
+}
+
+#[test]
+fn test_disallowed_scenarios_do_not_compile() {
+    // 허용되지 않는 시나리오 파일이 실제로 컴파일 오류를 내는지 trybuild로 확인
+    let cases = trybuild::TestCases::new();
+    for scenario in scenarios() {
+        if let ScenarioCheck::FailsToCompile { case } = scenario.check {
+            cases.compile_fail(case);
+        }
+    }
+}
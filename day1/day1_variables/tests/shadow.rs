@@ -0,0 +1,97 @@
+use day1_variables::{
+    JsonRenderer, MarkdownRenderer, PlainTextRenderer, ShadowStep, ShadowTrace, shadow_example,
+    shadow_step, shadow_trace,
+};
+
+#[test]
+fn test_shadow_trace_records_each_binding() {
+    let trace = shadow_trace();
+    let steps = trace.steps();
+
+    // 불변 3단계 + 가변 4단계
+    assert_eq!(steps.len(), 7);
+    assert_eq!(
+        steps[0],
+        ShadowStep {
+            step: 1,
+            binding: "immutable_value".to_string(),
+            value: "gyuseon Min".to_string(),
+            type_name: "&str".to_string(),
+            mutable: false,
+        }
+    );
+
+    // 섀도잉으로 타입이 &str -> String -> usize로 바뀌는지 확인
+    let types: Vec<&str> = steps[..3]
+        .iter()
+        .map(|step| step.type_name.as_str())
+        .collect();
+    assert_eq!(types, vec!["&str", "alloc::string::String", "usize"]);
+
+    // 재대입은 가변, 다시 let으로 섀도잉하면 불변 바인딩
+    let mutable: Vec<bool> = steps[3..].iter().map(|step| step.mutable).collect();
+    assert_eq!(mutable, vec![true, true, false, false]);
+    assert_eq!(steps[4].value, "gyuseon");
+    assert_eq!(steps[6].value, "7");
+}
+
+#[test]
+fn test_shadow_step_macro_keeps_bindings_usable() {
+    // 매크로가 만든 바인딩을 이후 코드에서 그대로 쓸 수 있는지 확인
+    let mut trace = ShadowTrace::new();
+    shadow_step!(trace, let slot = 42u64);
+    shadow_step!(trace, let slot = slot * 2);
+    shadow_step!(trace, let mut label = format!("slot {slot}"));
+    shadow_step!(trace, label = label.to_uppercase());
+
+    assert_eq!(slot, 84);
+    assert_eq!(label, "SLOT 84");
+    let steps: Vec<(usize, &str, &str)> = trace
+        .steps()
+        .iter()
+        .map(|step| (step.step, step.binding.as_str(), step.value.as_str()))
+        .collect();
+    assert_eq!(
+        steps,
+        vec![
+            (1, "slot", "42"),
+            (2, "slot", "84"),
+            (3, "label", "slot 84"),
+            (4, "label", "SLOT 84")
+        ]
+    );
+}
+
+#[test]
+fn test_shadow_trace_renderers() {
+    let trace = shadow_trace();
+
+    // 텍스트는 단계마다 한 줄이고, 바인딩 이름까지 보여 줌
+    let text = trace.render(&PlainTextRenderer);
+    assert!(text.starts_with("1. 불변 변수 immutable_value: 값: gyuseon Min, 타입: &str\n"));
+    assert_eq!(text.lines().count(), 7);
+
+    // JSON은 다시 읽으면 같은 기록
+    let json = trace.render(&JsonRenderer);
+    let parsed: Vec<ShadowStep> = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, trace.steps());
+
+    // 마크다운 표는 헤더 2줄 + 단계마다 한 줄, 값 안의 세로줄은 이스케이프
+    let mut piped = ShadowTrace::new();
+    piped.record("pipe", "a|b", false);
+    let table = piped.render(&MarkdownRenderer);
+    assert_eq!(
+        table,
+        "| 단계 | 바인딩 | 값 | 타입 | mut |\n|---|---|---|---|---|\n| 1 | `pipe` | a\\|b | `str` | no |\n"
+    );
+    assert_eq!(trace.render(&MarkdownRenderer).lines().count(), 9);
+}
+
+#[test]
+fn test_shadow_example_keeps_original_lines() {
+    // 새 렌더러가 생겨도 shadow_example의 기존 출력은 그대로
+    let result = shadow_example();
+    assert_eq!(result[0], "1. 불변 변수: 값: gyuseon Min, 타입: &str");
+    assert_eq!(result[4], "5. 가변 변수: 값: gyuseon, 타입: &str");
+    assert_eq!(result[6], "7. 가변 변수: 값: 7, 타입: usize");
+}
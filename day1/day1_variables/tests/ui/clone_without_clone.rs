@@ -0,0 +1,10 @@
+// Clone을 구현하지 않은 타입은 clone()으로 복제할 수 없습니다.
+struct SigningKey {
+    secret: Vec<u8>,
+}
+
+fn main() {
+    let key = SigningKey { secret: vec![1, 2, 3] };
+    let copy = key.clone();
+    println!("{} {}", key.secret.len(), copy.secret.len());
+}
@@ -0,0 +1,12 @@
+// 힙 데이터를 가진 필드(String)가 있으면 Copy를 구현할 수 없습니다.
+#[derive(Clone, Copy)]
+struct Wallet {
+    owner: String,
+    balance: u64,
+}
+
+fn main() {
+    let wallet = Wallet { owner: String::from("gyuseon"), balance: 10 };
+    let copied = wallet;
+    println!("{} {}", wallet.owner, copied.balance);
+}
@@ -0,0 +1,7 @@
+// 불변 참조가 살아 있는 동안에는 가변 참조를 만들 수 없습니다.
+fn main() {
+    let mut blocks = vec![100u64, 101];
+    let first = &blocks[0];
+    blocks.push(102);
+    println!("{first}");
+}
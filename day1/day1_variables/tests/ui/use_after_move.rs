@@ -0,0 +1,6 @@
+// 소유권이 after로 이동한 뒤에는 before를 쓸 수 없습니다.
+fn main() {
+    let before = String::from("gyuseon");
+    let after = before;
+    println!("{before} {after}");
+}
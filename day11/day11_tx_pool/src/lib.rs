@@ -0,0 +1,310 @@
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, btree_map};
+use std::iter::Peekable;
+
+// 멤풀 크레이트와 같은 32바이트 id를 쓰고, 수수료 규칙과 에러는 day6 스케줄러 것을 그대로 씁니다.
+pub use day5_mempool_pipeline::AccountId;
+pub use day6_fee_scheduler::{SchedulerError, TxId};
+
+// 풀에 들어오는 트랜잭션입니다.
+// 순서는 gas_price가 먼저 정하고, priority는 가스 가격이 같을 때 쓰는 추가 가중치(예: 평판 점수)입니다.
+// A pool transaction; gas price decides the order, priority only breaks gas-price ties.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingTransaction {
+    pub hash: TxId,
+    pub sender: AccountId,
+    pub nonce: u64,
+    pub gas_price: u64,
+    pub priority: u128,
+}
+
+impl PendingTransaction {
+    // (gas_price, priority) 순서의 튜플이라, 튜플 비교만으로 가스 가격이 먼저 비교되고 동점이면 priority를 봅니다.
+    pub fn priority_key(&self) -> (u64, u128) {
+        (self.gas_price, self.priority)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TxPoolConfig {
+    // Reth의 pending/queued 서브풀 크기 제한을 하나로 줄인 풀 전체 트랜잭션 수 한도
+    pub capacity: usize,
+    // Reth의 max_account_slots처럼 sender 하나가 차지할 수 있는 트랜잭션 수 한도
+    pub max_account_slots: usize,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum TxInsertError {
+    // 가스 가격 0은 day6 스케줄러와 같은 규칙으로 SchedulerError::FeeTooLow를 돌려줍니다.
+    #[error(transparent)]
+    Scheduler(#[from] SchedulerError),
+    // 같은 hash의 트랜잭션이 이미 풀에 있을 때 (sender가 달라도 거절합니다)
+    #[error("transaction {hash} is already in the pool")]
+    DuplicateHash { hash: TxId },
+    // 같은 sender의 같은 nonce가 이미 풀에 있을 때
+    #[error("sender {sender} already has a transaction with nonce {nonce}")]
+    DuplicateNonce { sender: AccountId, nonce: u64 },
+    // sender 큐가 max_account_slots만큼 차 있을 때
+    #[error("sender {sender} reached the account slot limit")]
+    AccountLimitReached { sender: AccountId },
+    // 풀이 가득 찼고, 새 트랜잭션이 가장 나쁜 head보다 낫지 않을 때
+    #[error("pool is full and the transaction does not outbid the worst head")]
+    PoolFull,
+}
+
+// 전역 큐에 들어가는 sender head입니다. 트랜잭션 자체는 per_account에만 두고, 여기에는 비교에 필요한 값만 복사합니다.
+// `Ord`는 day6의 ScheduledTx와 같은 규칙입니다. priority_key가 높은 것이 먼저이고, 동점이면 hash가 큰 쪽이 먼저입니다.
+// ScheduledTx를 그대로 쓰지 않는 이유: ScheduledTx는 u128 score 하나와 MempoolEntry(수수료, compute unit)를 비교하는데,
+// 풀의 키는 (u64, u128) 튜플이라 u128 하나에 손실 없이 담을 수 없고, 항목마다 어느 sender의 head인지도 알아야 합니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct QueuedTx {
+    key: (u64, u128),
+    hash: TxId,
+    sender: AccountId,
+}
+
+impl QueuedTx {
+    fn new(tx: &PendingTransaction) -> Self {
+        Self {
+            key: tx.priority_key(),
+            hash: tx.hash,
+            sender: tx.sender,
+        }
+    }
+}
+
+// Reth처럼 max-heap에서 가장 높은 우선순위를 먼저 꺼냅니다.
+// 풀 안의 hash는 유일하지만, derive한 Eq와 어긋나지 않도록 마지막에 sender까지 비교합니다.
+impl Ord for QueuedTx {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key
+            .cmp(&other.key)
+            .then_with(|| self.hash.cmp(&other.hash))
+            .then_with(|| self.sender.cmp(&other.sender))
+    }
+}
+
+impl PartialOrd for QueuedTx {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// 풀은 체인의 계정 nonce를 모르므로 nonce 구멍을 검사하지 않습니다.
+// sender의 가장 낮은 nonce를 head로 보고, nonce 0과 5만 있어도 pop과 best_transactions는 둘 다 차례로 내보냅니다.
+// 구멍 때문에 실행할 수 없는 트랜잭션은 실행 쪽에서 계정 nonce와 맞춰 걸러야 합니다.
+pub struct TxPool {
+    config: TxPoolConfig,
+    // sender마다 nonce → 트랜잭션. 가장 낮은 nonce(head)가 다음에 실행될 트랜잭션입니다.
+    per_account: HashMap<AccountId, BTreeMap<u64, PendingTransaction>>,
+    // sender head만 비교하는 전역 우선순위 큐. head가 바뀌어도 예전 항목을 바로 지우지 않고 꺼낼 때 거릅니다.
+    global_queue: BinaryHeap<QueuedTx>,
+    // 현재 sender head만 담는 정렬된 색인. 맨 앞이 가장 나쁜 head라서 풀이 가득 찼을 때 훑지 않고 바로 찾습니다.
+    head_index: BTreeSet<QueuedTx>,
+    // 풀에 있는 모든 트랜잭션의 hash → (sender, nonce). 같은 hash를 두 번 받지 않도록 씁니다.
+    by_hash: HashMap<TxId, (AccountId, u64)>,
+    total_txs: usize,
+}
+
+impl TxPool {
+    pub fn new(config: TxPoolConfig) -> Self {
+        Self {
+            config,
+            per_account: HashMap::new(),
+            global_queue: BinaryHeap::new(),
+            head_index: BTreeSet::new(),
+            by_hash: HashMap::new(),
+            total_txs: 0,
+        }
+    }
+
+    pub fn config(&self) -> &TxPoolConfig {
+        &self.config
+    }
+
+    // 풀에 있는 트랜잭션 수
+    pub fn len(&self) -> usize {
+        self.total_txs
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.total_txs == 0
+    }
+
+    // sender의 큐를 nonce 순서대로 돌려줍니다.
+    pub fn sender_queue(&self, sender: &AccountId) -> Option<&BTreeMap<u64, PendingTransaction>> {
+        self.per_account.get(sender)
+    }
+
+    // 전역 큐 크기. 아직 걸러지지 않은 stale 항목도 포함합니다.
+    pub fn global_queue_len(&self) -> usize {
+        self.global_queue.len()
+    }
+
+    // 트랜잭션을 sender 큐에 nonce 순서대로 넣습니다.
+    // 풀이 가득 차 있으면 가장 나쁜 head와 비교해서, 새 트랜잭션이 더 나을 때만 자리를 비우고 넣습니다.
+    // 자리를 비우려고 밀려난 트랜잭션이 있으면 Some으로 돌려줍니다.
+    pub fn insert(
+        &mut self,
+        tx: PendingTransaction,
+    ) -> Result<Option<PendingTransaction>, TxInsertError> {
+        if tx.gas_price == 0 {
+            return Err(SchedulerError::FeeTooLow.into());
+        }
+        let queue = self.per_account.get(&tx.sender);
+        if queue.is_some_and(|queue| queue.contains_key(&tx.nonce)) {
+            return Err(TxInsertError::DuplicateNonce {
+                sender: tx.sender,
+                nonce: tx.nonce,
+            });
+        }
+        // 같은 nonce 재제출은 DuplicateNonce로 먼저 알려 주고, 그 밖의 hash 충돌은 여기서 거릅니다.
+        if self.by_hash.contains_key(&tx.hash) {
+            return Err(TxInsertError::DuplicateHash { hash: tx.hash });
+        }
+        // 큐가 없는 새 sender도 0개로 보고 한도를 검사합니다. (max_account_slots가 0이면 아무도 못 넣습니다)
+        if queue.map_or(0, BTreeMap::len) >= self.config.max_account_slots {
+            return Err(TxInsertError::AccountLimitReached { sender: tx.sender });
+        }
+
+        let evicted = if self.total_txs >= self.config.capacity {
+            match self.worst_head() {
+                Some(worst) if tx.priority_key() > worst.key => self.evict_head(&worst),
+                _ => return Err(TxInsertError::PoolFull),
+            }
+        } else {
+            None
+        };
+
+        let sender = tx.sender;
+        let queue = self.per_account.entry(sender).or_default();
+        // 새 트랜잭션의 nonce가 가장 낮을 때만 head가 바뀝니다. 밀려날 예전 head는 미리 복사해 둡니다.
+        let becomes_head = queue
+            .first_key_value()
+            .is_none_or(|(&head_nonce, _)| tx.nonce < head_nonce);
+        let old_head = queue
+            .first_key_value()
+            .filter(|_| becomes_head)
+            .map(|(_, head)| QueuedTx::new(head));
+        self.by_hash.insert(tx.hash, (sender, tx.nonce));
+        queue.insert(tx.nonce, tx);
+        self.total_txs += 1;
+        if becomes_head {
+            if let Some(old_head) = &old_head {
+                self.head_index.remove(old_head);
+            }
+            self.refresh_head(&sender);
+        }
+        Ok(evicted)
+    }
+
+    // 가장 우선순위가 높은 head를 꺼내고, 그 sender의 다음 nonce를 전역 큐에 올립니다.
+    pub fn pop(&mut self) -> Option<PendingTransaction> {
+        while let Some(queued) = self.global_queue.pop() {
+            if !self.is_current_head(&queued) {
+                // lazy eviction: 이미 꺼냈거나 더 앞선 nonce에 밀린 항목은 여기서 버립니다.
+                continue;
+            }
+            let queue = self
+                .per_account
+                .get_mut(&queued.sender)
+                .expect("current head has a queue");
+            let (_, tx) = queue.pop_first().expect("current head exists");
+            self.head_index.remove(&queued);
+            self.by_hash.remove(&tx.hash);
+            if queue.is_empty() {
+                self.per_account.remove(&queued.sender);
+            } else {
+                self.refresh_head(&queued.sender);
+            }
+            self.total_txs -= 1;
+            return Some(tx);
+        }
+        None
+    }
+
+    // 풀을 바꾸지 않고 실행할 순서대로 트랜잭션을 보여 줍니다.
+    pub fn best_transactions(&self) -> BestTransactions<'_> {
+        let mut heads = BinaryHeap::new();
+        let mut cursors = HashMap::new();
+        for (sender, queue) in &self.per_account {
+            let mut values = queue.values().peekable();
+            if let Some(front) = values.peek() {
+                heads.push(QueuedTx::new(front));
+                cursors.insert(*sender, values);
+            }
+        }
+        BestTransactions { heads, cursors }
+    }
+
+    // 가장 나쁜 head. BinaryHeap은 최솟값을 꺼낼 수 없고 stale 항목도 섞여 있으므로, 현재 head만 담은 head_index의 맨 앞을 봅니다.
+    fn worst_head(&self) -> Option<QueuedTx> {
+        self.head_index.first().cloned()
+    }
+
+    // insert에서 새 트랜잭션과 비교한 그 head를 뺍니다. 비교한 트랜잭션과 빠지는 트랜잭션이 항상 같습니다.
+    // sender의 다음 nonce가 새 head가 됩니다. 전역 큐에 남은 예전 항목은 pop할 때 걸러집니다.
+    fn evict_head(&mut self, head: &QueuedTx) -> Option<PendingTransaction> {
+        let queue = self.per_account.get_mut(&head.sender)?;
+        let (_, evicted) = queue.pop_first()?;
+        self.head_index.remove(head);
+        self.by_hash.remove(&evicted.hash);
+        self.total_txs -= 1;
+        if queue.is_empty() {
+            self.per_account.remove(&head.sender);
+        } else {
+            self.refresh_head(&head.sender);
+        }
+        Some(evicted)
+    }
+
+    // sender의 현재 head를 전역 큐와 head_index에 올립니다. 예전 head는 부르는 쪽이 head_index에서 먼저 뺍니다.
+    // stale 항목이 너무 많이 쌓이면 실제 head들로 전역 큐를 다시 만듭니다.
+    fn refresh_head(&mut self, sender: &AccountId) {
+        if let Some((_, front)) = self
+            .per_account
+            .get(sender)
+            .and_then(|queue| queue.first_key_value())
+        {
+            self.global_queue.push(QueuedTx::new(front));
+            self.head_index.insert(QueuedTx::new(front));
+        }
+        if self.global_queue.len() > 2 * self.per_account.len() + 16 {
+            self.global_queue = self
+                .per_account
+                .values()
+                .filter_map(|queue| queue.values().next().map(QueuedTx::new))
+                .collect();
+        }
+    }
+
+    fn is_current_head(&self, queued: &QueuedTx) -> bool {
+        self.per_account
+            .get(&queued.sender)
+            .and_then(|queue| queue.values().next())
+            .is_some_and(|front| front.hash == queued.hash)
+    }
+}
+
+// best_transactions()가 돌려주는 반복자입니다. 풀의 스냅샷을 빌려서 읽기만 합니다.
+// 전역으로는 head끼리만 비교하므로, sender의 트랜잭션은 항상 nonce 순서로 나옵니다. pop을 반복한 순서와 같습니다.
+pub struct BestTransactions<'a> {
+    heads: BinaryHeap<QueuedTx>,
+    // sender마다 아직 내보내지 않은 트랜잭션. peek한 값이 heads에 올라가 있는 그 sender의 head입니다.
+    cursors: HashMap<AccountId, Peekable<btree_map::Values<'a, u64, PendingTransaction>>>,
+}
+
+impl<'a> Iterator for BestTransactions<'a> {
+    type Item = &'a PendingTransaction;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let queued = self.heads.pop()?;
+        let values = self.cursors.get_mut(&queued.sender)?;
+        let tx = values.next()?;
+        // 같은 sender의 다음 nonce는 지금 것을 내보낸 뒤에야 후보가 됩니다.
+        if let Some(next) = values.peek() {
+            self.heads.push(QueuedTx::new(next));
+        }
+        Some(tx)
+    }
+}
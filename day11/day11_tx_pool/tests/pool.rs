@@ -0,0 +1,213 @@
+// 전역/계정별 우선순위 동시 검증: sender 안에서는 nonce 순서, sender끼리는 head의 가스 가격 순서를 지키는지 확인합니다.
+
+use day11_tx_pool::{
+    AccountId, PendingTransaction, SchedulerError, TxId, TxInsertError, TxPool, TxPoolConfig,
+};
+
+fn account(label: &str) -> AccountId {
+    let mut bytes = [0u8; 32];
+    bytes[..label.len()].copy_from_slice(label.as_bytes());
+    AccountId::new(bytes)
+}
+
+// sender와 nonce로 서로 다른 hash를 만듭니다.
+fn tx(sender: &str, nonce: u64, gas_price: u64) -> PendingTransaction {
+    let mut bytes = [0u8; 32];
+    bytes[..sender.len()].copy_from_slice(sender.as_bytes());
+    bytes[24..].copy_from_slice(&nonce.to_be_bytes());
+    PendingTransaction {
+        hash: TxId::new(bytes),
+        sender: account(sender),
+        nonce,
+        gas_price,
+        priority: 0,
+    }
+}
+
+fn pool(capacity: usize, max_account_slots: usize) -> TxPool {
+    TxPool::new(TxPoolConfig {
+        capacity,
+        max_account_slots,
+    })
+}
+
+fn summary<'a>(txs: impl IntoIterator<Item = &'a PendingTransaction>) -> Vec<(AccountId, u64)> {
+    txs.into_iter().map(|tx| (tx.sender, tx.nonce)).collect()
+}
+
+#[test]
+fn sender_queue_stays_nonce_ordered() {
+    let mut pool = pool(10, 5);
+    // nonce를 뒤섞어 넣어도 sender 큐는 nonce 오름차순
+    for nonce in [2, 0, 1] {
+        pool.insert(tx("alice", nonce, 10 + nonce)).unwrap();
+    }
+    let nonces: Vec<u64> = pool
+        .sender_queue(&account("alice"))
+        .unwrap()
+        .values()
+        .map(|tx| tx.nonce)
+        .collect();
+    assert_eq!(nonces, vec![0, 1, 2]);
+
+    // 뒤 nonce의 가스 가격이 더 높아도 head(nonce 0)부터 나옴
+    let popped: Vec<u64> = std::iter::from_fn(|| pool.pop())
+        .map(|tx| tx.nonce)
+        .collect();
+    assert_eq!(popped, vec![0, 1, 2]);
+    assert!(pool.is_empty());
+    assert_eq!(pool.pop(), None);
+}
+
+#[test]
+fn global_queue_compares_heads_by_gas_price() {
+    let mut pool = pool(10, 5);
+    pool.insert(tx("alice", 0, 5)).unwrap();
+    pool.insert(tx("alice", 1, 50)).unwrap();
+    pool.insert(tx("bob", 0, 20)).unwrap();
+    pool.insert(tx("carol", 0, 10)).unwrap();
+
+    // 전역 큐에는 sender head만 올라가 있음
+    assert_eq!(pool.global_queue_len(), 3);
+
+    // alice의 nonce 1(50)은 head(5)가 나가기 전에는 후보가 아님
+    let expected = vec![
+        (account("bob"), 0),
+        (account("carol"), 0),
+        (account("alice"), 0),
+        (account("alice"), 1),
+    ];
+    // best_transactions는 풀을 바꾸지 않고 pop과 같은 순서를 보여 줌
+    assert_eq!(summary(pool.best_transactions()), expected);
+    assert_eq!(pool.len(), 4);
+
+    let popped: Vec<_> = std::iter::from_fn(|| pool.pop()).collect();
+    assert_eq!(summary(&popped), expected);
+}
+
+#[test]
+fn full_pool_evicts_worst_head_or_rejects() {
+    let mut pool = pool(3, 5);
+    pool.insert(tx("alice", 0, 30)).unwrap();
+    pool.insert(tx("bob", 0, 5)).unwrap();
+    pool.insert(tx("bob", 1, 40)).unwrap();
+
+    // 가장 나쁜 head(bob, 5)보다 낮거나 같으면 거절
+    assert!(matches!(
+        pool.insert(tx("carol", 0, 5)),
+        Err(TxInsertError::PoolFull)
+    ));
+
+    // 더 높으면 비교한 그 head(bob의 nonce 0)를 빼고, bob의 nonce 1이 새 head가 됨
+    let evicted = pool.insert(tx("carol", 0, 20)).unwrap().unwrap();
+    assert_eq!((evicted.sender, evicted.nonce), (account("bob"), 0));
+    assert_eq!(pool.len(), 3);
+    assert_eq!(pool.sender_queue(&account("bob")).unwrap().len(), 1);
+
+    // 이제 가장 나쁜 head는 carol(20)
+    let evicted = pool.insert(tx("dave", 0, 25)).unwrap().unwrap();
+    assert_eq!((evicted.sender, evicted.nonce), (account("carol"), 0));
+    assert!(pool.sender_queue(&account("carol")).is_none());
+
+    // 빠진 항목은 전역 큐에 남아 있어도 pop에서 걸러짐
+    let popped: Vec<_> = std::iter::from_fn(|| pool.pop()).collect();
+    assert_eq!(
+        summary(&popped),
+        vec![
+            (account("bob"), 1),
+            (account("alice"), 0),
+            (account("dave"), 0),
+        ]
+    );
+}
+
+#[test]
+fn lower_nonce_replaces_head_for_eviction() {
+    let mut pool = pool(3, 5);
+    pool.insert(tx("alice", 1, 50)).unwrap();
+    pool.insert(tx("bob", 0, 10)).unwrap();
+    // 더 낮은 nonce가 들어오면 alice의 head는 가스 가격 2짜리로 바뀜
+    pool.insert(tx("alice", 0, 2)).unwrap();
+
+    // 가장 나쁜 head는 이제 alice(2)라서 carol(3)이 들어가고 그 head가 빠짐
+    let evicted = pool.insert(tx("carol", 0, 3)).unwrap().unwrap();
+    assert_eq!((evicted.sender, evicted.nonce), (account("alice"), 0));
+
+    // alice의 head는 다시 nonce 1(50)이고, 가장 나쁜 head는 carol(3)
+    let evicted = pool.insert(tx("dave", 0, 4)).unwrap().unwrap();
+    assert_eq!((evicted.sender, evicted.nonce), (account("carol"), 0));
+    assert!(matches!(
+        pool.insert(tx("erin", 0, 4)),
+        Err(TxInsertError::PoolFull)
+    ));
+}
+
+#[test]
+fn nonce_gaps_are_not_checked() {
+    let mut pool = pool(10, 5);
+    pool.insert(tx("alice", 5, 10)).unwrap();
+    pool.insert(tx("alice", 0, 10)).unwrap();
+
+    // 풀은 체인 nonce를 모르므로 0 다음에 5를 그대로 내보냄 (구멍 검사는 실행 쪽 몫)
+    let expected = vec![(account("alice"), 0), (account("alice"), 5)];
+    assert_eq!(summary(pool.best_transactions()), expected);
+    let popped: Vec<_> = std::iter::from_fn(|| pool.pop()).collect();
+    assert_eq!(summary(&popped), expected);
+}
+
+#[test]
+fn insert_rejects_invalid_transactions() {
+    let mut pool = pool(10, 2);
+    assert!(matches!(
+        pool.insert(tx("alice", 0, 0)),
+        Err(TxInsertError::Scheduler(SchedulerError::FeeTooLow))
+    ));
+
+    pool.insert(tx("alice", 0, 10)).unwrap();
+    assert!(matches!(
+        pool.insert(tx("alice", 0, 99)),
+        Err(TxInsertError::DuplicateNonce { nonce: 0, .. })
+    ));
+
+    pool.insert(tx("alice", 1, 10)).unwrap();
+    assert!(matches!(
+        pool.insert(tx("alice", 2, 10)),
+        Err(TxInsertError::AccountLimitReached { .. })
+    ));
+    assert_eq!(pool.len(), 2);
+}
+
+#[test]
+fn duplicate_hash_is_rejected_across_senders() {
+    let mut pool = pool(10, 5);
+    let first = tx("alice", 0, 10);
+    pool.insert(first.clone()).unwrap();
+
+    // 다른 sender라도 같은 hash면 거절하고, head 색인에는 alice 하나만 남음
+    let mut copy = tx("bob", 0, 10);
+    copy.hash = first.hash;
+    assert!(matches!(
+        pool.insert(copy),
+        Err(TxInsertError::DuplicateHash { hash }) if hash == first.hash
+    ));
+    assert_eq!(pool.len(), 1);
+    assert!(pool.sender_queue(&account("bob")).is_none());
+
+    // 꺼낸 뒤에는 같은 hash를 다시 받을 수 있음
+    assert_eq!(pool.pop(), Some(first.clone()));
+    pool.insert(first).unwrap();
+    assert_eq!(pool.len(), 1);
+}
+
+#[test]
+fn account_slot_limit_applies_to_new_senders() {
+    // 한도가 0이면 큐가 없는 새 sender도 받지 않음
+    let mut pool = pool(10, 0);
+    assert!(matches!(
+        pool.insert(tx("bob", 0, 10)),
+        Err(TxInsertError::AccountLimitReached { .. })
+    ));
+    assert!(pool.is_empty());
+    assert!(pool.sender_queue(&account("bob")).is_none());
+    assert_eq!(pool.global_queue_len(), 0);
+}
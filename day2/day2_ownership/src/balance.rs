@@ -0,0 +1,74 @@
+use std::fmt;
+
+// 음수가 될 수 없는 잔액(lamports)입니다. 모든 연산이 checked 또는 saturating이라 넘침으로 panic하거나 wrap되지 않습니다.
+// A non-negative balance whose arithmetic never panics or wraps.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Balance(u64);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum BalanceError {
+    #[error("잔액은 음수가 될 수 없습니다. 현재: {balance}, 변경: {delta}")]
+    Negative { balance: u64, delta: i64 },
+    #[error("잔액이 u64 범위를 넘습니다. 현재: {balance}, 변경: {delta}")]
+    Overflow { balance: u64, delta: i64 },
+}
+
+impl Balance {
+    pub const ZERO: Balance = Balance(0);
+    pub const MAX: Balance = Balance(u64::MAX);
+
+    pub const fn new(lamports: u64) -> Self {
+        Balance(lamports)
+    }
+
+    pub const fn lamports(self) -> u64 {
+        self.0
+    }
+
+    // 부호 있는 변경값을 적용합니다. 음수가 되거나 넘치면 어느 쪽인지 알려 줍니다.
+    pub fn checked_apply(self, delta: i64) -> Result<Balance, BalanceError> {
+        match self.0.checked_add_signed(delta) {
+            Some(lamports) => Ok(Balance(lamports)),
+            None if delta < 0 => Err(BalanceError::Negative {
+                balance: self.0,
+                delta,
+            }),
+            None => Err(BalanceError::Overflow {
+                balance: self.0,
+                delta,
+            }),
+        }
+    }
+
+    // 0과 u64::MAX 사이로 잘라 냅니다. 수수료 추정처럼 대략적인 값이면 충분할 때 씁니다.
+    pub fn saturating_apply(self, delta: i64) -> Balance {
+        Balance(self.0.saturating_add_signed(delta))
+    }
+
+    pub fn checked_add(self, amount: u64) -> Option<Balance> {
+        self.0.checked_add(amount).map(Balance)
+    }
+
+    pub fn checked_sub(self, amount: u64) -> Option<Balance> {
+        self.0.checked_sub(amount).map(Balance)
+    }
+}
+
+impl From<u64> for Balance {
+    fn from(lamports: u64) -> Self {
+        Balance(lamports)
+    }
+}
+
+impl fmt::Display for Balance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+// mutate_wallet과 같은 일을 하지만, 잔액을 Balance로 받고 실패 이유를 타입으로 돌려줍니다.
+// 실패하면 잔액은 바뀌지 않습니다.
+pub fn try_mutate_wallet(balance: &mut Balance, delta: i64) -> Result<(), BalanceError> {
+    *balance = balance.checked_apply(delta)?;
+    Ok(())
+}
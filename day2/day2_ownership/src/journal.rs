@@ -0,0 +1,145 @@
+use std::fmt::Debug;
+
+use crate::ledger::{BatchError, Ledger, LedgerError, Transfer};
+
+// 실행 엔진이 실패한 트랜잭션을 되돌리듯, 체크포인트를 찍고 그 뒤의 잔액 변경을 되돌리거나 확정합니다.
+// 체크포인트는 중첩할 수 있고, 바깥 체크포인트로 되돌리면 안쪽 체크포인트도 함께 사라집니다.
+// A ledger with a change journal supporting nested checkpoints, commit and revert.
+#[derive(Debug, Clone)]
+pub struct JournaledLedger<K: Ord> {
+    ledger: Ledger<K>,
+    // 바뀌기 전 잔액 기록. None이면 그 계정이 없었습니다.
+    journal: Vec<(K, Option<u64>)>,
+    // 열린 체크포인트마다 (id, 찍을 당시의 journal 길이)
+    checkpoints: Vec<(u64, usize)>,
+    next_checkpoint_id: u64,
+}
+
+// checkpoint()가 돌려주는 표식입니다. 확정하거나 되돌리면 더는 쓸 수 없습니다.
+// 같은 깊이에 새 체크포인트를 찍어도 예전 표식이 되살아나지 않도록 id로 구분합니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Checkpoint(u64);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum JournalError {
+    #[error("checkpoint was already committed or reverted")]
+    UnknownCheckpoint,
+}
+
+impl<K: Ord + Clone + Debug> JournaledLedger<K> {
+    pub fn new(ledger: Ledger<K>) -> Self {
+        Self {
+            ledger,
+            journal: Vec::new(),
+            checkpoints: Vec::new(),
+            next_checkpoint_id: 0,
+        }
+    }
+
+    // 읽기는 안쪽 Ledger로 합니다.
+    pub fn ledger(&self) -> &Ledger<K> {
+        &self.ledger
+    }
+
+    pub fn into_inner(self) -> Ledger<K> {
+        self.ledger
+    }
+
+    // 열려 있는 체크포인트 수
+    pub fn depth(&self) -> usize {
+        self.checkpoints.len()
+    }
+
+    pub fn checkpoint(&mut self) -> Checkpoint {
+        let id = self.next_checkpoint_id;
+        self.next_checkpoint_id += 1;
+        self.checkpoints.push((id, self.journal.len()));
+        Checkpoint(id)
+    }
+
+    // checkpoint 이후의 변경을 모두 되돌립니다. 그 안쪽 체크포인트들도 닫힙니다.
+    pub fn revert_to(&mut self, checkpoint: Checkpoint) -> Result<(), JournalError> {
+        let (position, journal_len) = self.open_checkpoint(checkpoint)?;
+        // 나중에 바뀐 것부터 거꾸로 되돌려야 같은 계정이 여러 번 바뀌어도 처음 값으로 돌아갑니다.
+        while self.journal.len() > journal_len {
+            let (account, previous) = self.journal.pop().expect("journal longer than mark");
+            self.ledger.restore(account, previous);
+        }
+        self.checkpoints.truncate(position);
+        Ok(())
+    }
+
+    // checkpoint 이후의 변경을 확정합니다. 바깥 체크포인트가 남아 있으면 그쪽에서는 여전히 되돌릴 수 있습니다.
+    pub fn commit(&mut self, checkpoint: Checkpoint) -> Result<(), JournalError> {
+        let (position, _) = self.open_checkpoint(checkpoint)?;
+        self.checkpoints.truncate(position);
+        if self.checkpoints.is_empty() {
+            self.journal.clear();
+        }
+        Ok(())
+    }
+
+    pub fn open_account(&mut self, account: K) -> bool {
+        let previous = self.ledger.balance(&account);
+        let opened = self.ledger.open_account(account.clone());
+        if opened {
+            self.record(account, previous);
+        }
+        opened
+    }
+
+    pub fn deposit(&mut self, account: &K, amount: u64) -> Result<u64, LedgerError<K>> {
+        let previous = self.ledger.balance(account);
+        let balance = self.ledger.deposit(account, amount)?;
+        self.record(account.clone(), previous);
+        Ok(balance)
+    }
+
+    pub fn withdraw(&mut self, account: &K, amount: u64) -> Result<u64, LedgerError<K>> {
+        let previous = self.ledger.balance(account);
+        let balance = self.ledger.withdraw(account, amount)?;
+        self.record(account.clone(), previous);
+        Ok(balance)
+    }
+
+    pub fn transfer(&mut self, from: &K, to: &K, amount: u64) -> Result<(), LedgerError<K>> {
+        let previous = [
+            (from.clone(), self.ledger.balance(from)),
+            (to.clone(), self.ledger.balance(to)),
+        ];
+        self.ledger.transfer(from, to, amount)?;
+        for (account, balance) in previous {
+            self.record(account, balance);
+        }
+        Ok(())
+    }
+
+    pub fn apply_batch(&mut self, transfers: &[Transfer<K>]) -> Result<(), BatchError<K>> {
+        let previous: Vec<(K, Option<u64>)> = transfers
+            .iter()
+            .flat_map(|transfer| [&transfer.from, &transfer.to])
+            .map(|account| (account.clone(), self.ledger.balance(account)))
+            .collect();
+        self.ledger.apply_batch(transfers)?;
+        for (account, balance) in previous {
+            self.record(account, balance);
+        }
+        Ok(())
+    }
+
+    // 열린 체크포인트가 없으면 되돌릴 일이 없으므로 기록하지 않습니다.
+    fn record(&mut self, account: K, previous: Option<u64>) {
+        if !self.checkpoints.is_empty() {
+            self.journal.push((account, previous));
+        }
+    }
+
+    // 열린 체크포인트의 (스택 위치, journal 길이)
+    fn open_checkpoint(&self, checkpoint: Checkpoint) -> Result<(usize, usize), JournalError> {
+        self.checkpoints
+            .iter()
+            .position(|&(id, _)| id == checkpoint.0)
+            .map(|position| (position, self.checkpoints[position].1))
+            .ok_or(JournalError::UnknownCheckpoint)
+    }
+}
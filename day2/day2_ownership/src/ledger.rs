@@ -0,0 +1,210 @@
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+
+// mutate_wallet이 잔액 하나를 다룬다면, Ledger는 여러 계정의 잔액을 한꺼번에 다룹니다.
+// 잔액은 음수가 될 수 없으므로 u64로 두고, 모든 덧셈/뺄셈은 checked 연산으로 검사합니다.
+// 계정 키는 제네릭이라 문자열이나 멤풀의 AccountId를 그대로 쓸 수 있습니다.
+// Many account balances with checked arithmetic and all-or-nothing transfers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ledger<K: Ord> {
+    balances: BTreeMap<K, u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum LedgerError<K: Debug> {
+    #[error("account {account:?} has {balance}, cannot pay {amount}")]
+    InsufficientFunds {
+        account: K,
+        balance: u64,
+        amount: u64,
+    },
+    #[error("account {account:?} would overflow: {balance} + {amount}")]
+    Overflow {
+        account: K,
+        balance: u64,
+        amount: u64,
+    },
+    #[error("unknown account {account:?}")]
+    UnknownAccount { account: K },
+}
+
+// 배치 안에서 몇 번째 이체가 실패했는지 알려 줍니다. 실패하면 배치 전체가 적용되지 않습니다.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("transfer #{index} failed, batch not applied: {error}")]
+pub struct BatchError<K: Debug> {
+    pub index: usize,
+    pub error: LedgerError<K>,
+}
+
+// 배치에 넣는 이체 한 건
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Transfer<K> {
+    pub from: K,
+    pub to: K,
+    pub amount: u64,
+}
+
+impl<K: Ord> Default for Ledger<K> {
+    fn default() -> Self {
+        Self {
+            balances: BTreeMap::new(),
+        }
+    }
+}
+
+impl<K: Ord + Clone + Debug> Ledger<K> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // 잔액 0인 계정을 엽니다. 이미 있으면 잔액을 건드리지 않고 false를 돌려줍니다.
+    pub fn open_account(&mut self, account: K) -> bool {
+        if self.balances.contains_key(&account) {
+            return false;
+        }
+        self.balances.insert(account, 0);
+        true
+    }
+
+    pub fn balance(&self, account: &K) -> Option<u64> {
+        self.balances.get(account).copied()
+    }
+
+    // 수수료 등을 낼 수 있는지 미리 확인합니다. 모르는 계정은 낼 수 없습니다.
+    pub fn can_afford(&self, account: &K, amount: u64) -> bool {
+        self.balance(account)
+            .is_some_and(|balance| balance >= amount)
+    }
+
+    // 전체 잔액 합. 계정이 많으면 u64를 넘을 수 있으므로 u128로 셉니다.
+    pub fn total(&self) -> u128 {
+        self.balances.values().map(|&balance| balance as u128).sum()
+    }
+
+    pub fn accounts(&self) -> impl Iterator<Item = (&K, u64)> {
+        self.balances
+            .iter()
+            .map(|(account, &balance)| (account, balance))
+    }
+
+    pub fn len(&self) -> usize {
+        self.balances.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.balances.is_empty()
+    }
+
+    // 입금 후 잔액을 돌려줍니다.
+    pub fn deposit(&mut self, account: &K, amount: u64) -> Result<u64, LedgerError<K>> {
+        let balance = self.balance_mut(account)?;
+        *balance = checked_credit(account, *balance, amount)?;
+        Ok(*balance)
+    }
+
+    // 출금 후 잔액을 돌려줍니다.
+    pub fn withdraw(&mut self, account: &K, amount: u64) -> Result<u64, LedgerError<K>> {
+        let balance = self.balance_mut(account)?;
+        *balance = checked_debit(account, *balance, amount)?;
+        Ok(*balance)
+    }
+
+    // 두 계정을 모두 검사한 다음에만 잔액을 바꾸므로, 실패하면 어느 쪽도 바뀌지 않습니다.
+    pub fn transfer(&mut self, from: &K, to: &K, amount: u64) -> Result<(), LedgerError<K>> {
+        let from_balance = self.balance_or_unknown(from)?;
+        let to_balance = self.balance_or_unknown(to)?;
+        let debited = checked_debit(from, from_balance, amount)?;
+        if from == to {
+            // 자기 자신에게 보내면 잔액만 확인하고 바뀌는 것은 없습니다.
+            return Ok(());
+        }
+        let credited = checked_credit(to, to_balance, amount)?;
+        self.balances.insert(from.clone(), debited);
+        self.balances.insert(to.clone(), credited);
+        Ok(())
+    }
+
+    // 여러 이체를 순서대로 적용합니다. 하나라도 실패하면 아무것도 적용하지 않습니다.
+    // 바뀐 잔액은 작업용 사본에만 모았다가, 모두 성공했을 때 한 번에 반영합니다.
+    pub fn apply_batch(&mut self, transfers: &[Transfer<K>]) -> Result<(), BatchError<K>> {
+        let mut staged: BTreeMap<&K, u64> = BTreeMap::new();
+        for (index, transfer) in transfers.iter().enumerate() {
+            let fail = |error| BatchError { index, error };
+            let from_balance = match staged.get(&transfer.from) {
+                Some(&balance) => balance,
+                None => self.balance_or_unknown(&transfer.from).map_err(fail)?,
+            };
+            let to_balance = match staged.get(&transfer.to) {
+                Some(&balance) => balance,
+                None => self.balance_or_unknown(&transfer.to).map_err(fail)?,
+            };
+            let debited =
+                checked_debit(&transfer.from, from_balance, transfer.amount).map_err(fail)?;
+            if transfer.from == transfer.to {
+                continue;
+            }
+            let credited =
+                checked_credit(&transfer.to, to_balance, transfer.amount).map_err(fail)?;
+            staged.insert(&transfer.from, debited);
+            staged.insert(&transfer.to, credited);
+        }
+
+        let staged: Vec<(K, u64)> = staged
+            .into_iter()
+            .map(|(account, balance)| (account.clone(), balance))
+            .collect();
+        self.balances.extend(staged);
+        Ok(())
+    }
+
+    // 저널이 되돌릴 때 씁니다. None이면 계정이 없던 상태로 돌립니다.
+    pub(crate) fn restore(&mut self, account: K, previous: Option<u64>) {
+        match previous {
+            Some(balance) => self.balances.insert(account, balance),
+            None => self.balances.remove(&account),
+        };
+    }
+
+    fn balance_or_unknown(&self, account: &K) -> Result<u64, LedgerError<K>> {
+        self.balance(account)
+            .ok_or_else(|| LedgerError::UnknownAccount {
+                account: account.clone(),
+            })
+    }
+
+    fn balance_mut(&mut self, account: &K) -> Result<&mut u64, LedgerError<K>> {
+        self.balances
+            .get_mut(account)
+            .ok_or_else(|| LedgerError::UnknownAccount {
+                account: account.clone(),
+            })
+    }
+}
+
+fn checked_debit<K: Clone + Debug>(
+    account: &K,
+    balance: u64,
+    amount: u64,
+) -> Result<u64, LedgerError<K>> {
+    balance
+        .checked_sub(amount)
+        .ok_or_else(|| LedgerError::InsufficientFunds {
+            account: account.clone(),
+            balance,
+            amount,
+        })
+}
+
+fn checked_credit<K: Clone + Debug>(
+    account: &K,
+    balance: u64,
+    amount: u64,
+) -> Result<u64, LedgerError<K>> {
+    balance
+        .checked_add(amount)
+        .ok_or_else(|| LedgerError::Overflow {
+            account: account.clone(),
+            balance,
+            amount,
+        })
+}
@@ -0,0 +1,429 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use proc_macro2::Span;
+use syn::spanned::Spanned;
+use syn::visit::{self, Visit};
+use syn::{Expr, FnArg, Lit, Pat, Type, UnOp};
+
+// annotate_borrowing과 describe_mutability가 정해진 설명문을 돌려준다면, 이 분석기는 실제 코드 조각을 syn으로 파싱해
+// let / let mut, 섀도잉, 소유권 이동, &·&mut 빌림을 줄 위치와 함께 찾아 줍니다.
+// 타입 검사는 하지 않으므로 이동은 "Copy가 아닐 수도 있는 값"을 넘기는 곳을 짚어 주는 정도입니다.
+// Parses a Rust snippet with syn and reports bindings, shadowing, moves and borrows with line spans.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FindingKind {
+    ImmutableBinding,
+    MutableBinding,
+    Shadowing,
+    Move,
+    SharedBorrow,
+    MutableBorrow,
+}
+
+impl FindingKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            FindingKind::ImmutableBinding => "let",
+            FindingKind::MutableBinding => "let mut",
+            FindingKind::Shadowing => "shadowing",
+            FindingKind::Move => "move",
+            FindingKind::SharedBorrow => "&",
+            FindingKind::MutableBorrow => "&mut",
+        }
+    }
+}
+
+// 찾은 항목 하나. line과 column은 1부터 셉니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finding {
+    pub kind: FindingKind,
+    pub line: usize,
+    pub column: usize,
+    // 변수 이름, 빌림이면 빌리는 식의 원문
+    pub subject: String,
+    pub explanation: String,
+}
+
+// 코드 조각 하나의 분석 결과. 항목은 코드에 나온 순서대로입니다.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LintReport {
+    pub findings: Vec<Finding>,
+}
+
+impl LintReport {
+    pub fn of_kind(&self, kind: FindingKind) -> impl Iterator<Item = &Finding> {
+        self.findings
+            .iter()
+            .filter(move |finding| finding.kind == kind)
+    }
+}
+
+impl fmt::Display for LintReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for finding in &self.findings {
+            writeln!(
+                f,
+                "{}:{} [{}] {}",
+                finding.line,
+                finding.column,
+                finding.kind.label(),
+                finding.explanation
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("코드 조각을 파싱할 수 없습니다 ({line}:{column}): {message}")]
+pub struct SnippetParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+// 함수 본문 조각(문장 나열)도 분석할 수 있도록 감싸는 머리말. 줄바꿈이 없어 줄 번호가 그대로 유지됩니다.
+const STATEMENT_WRAPPER: &str = "fn __snippet() {";
+
+// 코드 조각을 분석합니다. 파일(아이템 나열)로 먼저 읽어 보고, 안 되면 함수 본문으로 감싸 다시 읽습니다.
+pub fn analyze_snippet(source: &str) -> Result<LintReport, SnippetParseError> {
+    if let Ok(file) = syn::parse_file(source) {
+        let mut analyzer = Analyzer::new(source, 0);
+        analyzer.visit_file(&file);
+        return Ok(analyzer.finish());
+    }
+
+    let wrapped = format!("{STATEMENT_WRAPPER}{source}\n}}");
+    let offset = STATEMENT_WRAPPER.chars().count();
+    match syn::parse_file(&wrapped) {
+        Ok(file) => {
+            let mut analyzer = Analyzer::new(&wrapped, offset);
+            analyzer.visit_file(&file);
+            Ok(analyzer.finish())
+        }
+        Err(error) => {
+            let (line, column) = position(error.span(), offset);
+            Err(SnippetParseError {
+                line,
+                column,
+                message: error.to_string(),
+            })
+        }
+    }
+}
+
+// 1부터 세는 (줄, 칸). 감싼 조각이면 첫 줄의 칸에서 머리말 길이를 뺍니다.
+fn position(span: Span, first_line_offset: usize) -> (usize, usize) {
+    let start = span.start();
+    let column = if start.line == 1 {
+        start.column.saturating_sub(first_line_offset)
+    } else {
+        start.column
+    };
+    (start.line, column + 1)
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Binding {
+    line: usize,
+    // 타입이나 초기값으로 보아 Copy가 분명한지. 모르면 false로 두고 이동으로 봅니다.
+    copy: bool,
+}
+
+struct Analyzer<'a> {
+    source: &'a str,
+    first_line_offset: usize,
+    scopes: Vec<HashMap<String, Binding>>,
+    findings: Vec<Finding>,
+}
+
+impl<'a> Analyzer<'a> {
+    fn new(source: &'a str, first_line_offset: usize) -> Self {
+        Self {
+            source,
+            first_line_offset,
+            scopes: vec![HashMap::new()],
+            findings: Vec::new(),
+        }
+    }
+
+    fn finish(mut self) -> LintReport {
+        self.findings
+            .sort_by_key(|finding| (finding.line, finding.column));
+        LintReport {
+            findings: self.findings,
+        }
+    }
+
+    fn push(&mut self, kind: FindingKind, span: Span, subject: String, explanation: String) {
+        let (line, column) = position(span, self.first_line_offset);
+        self.findings.push(Finding {
+            kind,
+            line,
+            column,
+            subject,
+            explanation,
+        });
+    }
+
+    fn lookup(&self, name: &str) -> Option<Binding> {
+        self.scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.get(name).copied())
+    }
+
+    fn source_text(&self, span: Span) -> String {
+        self.source
+            .get(span.byte_range())
+            .unwrap_or_default()
+            .to_string()
+    }
+
+    // 패턴 안의 모든 이름을 현재 스코프에 묶습니다. report가 false면 (함수 인자처럼) 항목은 남기지 않습니다.
+    fn bind_pattern(&mut self, pat: &Pat, copy: bool, report: bool) {
+        match pat {
+            Pat::Ident(pat_ident) => {
+                let name = pat_ident.ident.to_string();
+                let span = pat_ident.ident.span();
+                let (line, _) = position(span, self.first_line_offset);
+                if report {
+                    if let Some(previous) = self.lookup(&name) {
+                        self.push(
+                            FindingKind::Shadowing,
+                            span,
+                            name.clone(),
+                            format!(
+                                "`{name}`가 {}번째 줄의 같은 이름 변수를 가립니다(shadowing). 새 변수라 타입이 달라도 됩니다.",
+                                previous.line
+                            ),
+                        );
+                    }
+                    if pat_ident.mutability.is_some() {
+                        self.push(
+                            FindingKind::MutableBinding,
+                            span,
+                            name.clone(),
+                            format!("`{name}`는 가변(mutable) 바인딩입니다. 값을 다시 대입하거나 &mut로 빌릴 수 있습니다."),
+                        );
+                    } else {
+                        self.push(
+                            FindingKind::ImmutableBinding,
+                            span,
+                            name.clone(),
+                            format!("`{name}`는 불변(immutable) 바인딩입니다. 값을 바꾸려면 let mut이 필요합니다."),
+                        );
+                    }
+                }
+                let copy = copy || pat_ident.by_ref.is_some();
+                self.scopes
+                    .last_mut()
+                    .expect("analyzer always has a scope")
+                    .insert(name, Binding { line, copy });
+                if let Some((_, subpat)) = &pat_ident.subpat {
+                    self.bind_pattern(subpat, copy, report);
+                }
+            }
+            Pat::Type(pat_type) => {
+                let copy = copy || is_copy_type(&pat_type.ty);
+                self.bind_pattern(&pat_type.pat, copy, report);
+            }
+            Pat::Reference(pat_ref) => self.bind_pattern(&pat_ref.pat, copy, report),
+            Pat::Tuple(tuple) => {
+                for elem in &tuple.elems {
+                    self.bind_pattern(elem, copy, report);
+                }
+            }
+            Pat::TupleStruct(tuple) => {
+                for elem in &tuple.elems {
+                    self.bind_pattern(elem, copy, report);
+                }
+            }
+            Pat::Struct(pat_struct) => {
+                for field in &pat_struct.fields {
+                    self.bind_pattern(&field.pat, copy, report);
+                }
+            }
+            Pat::Slice(slice) => {
+                for elem in &slice.elems {
+                    self.bind_pattern(elem, copy, report);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // 값이 필요한 자리에 변수 이름만 그대로 오면, Copy가 아닌 한 소유권이 이동합니다.
+    fn check_move(&mut self, expr: &Expr) {
+        let Expr::Path(path) = expr else {
+            return;
+        };
+        let Some(ident) = path.path.get_ident() else {
+            return;
+        };
+        let name = ident.to_string();
+        if let Some(binding) = self.lookup(&name)
+            && !binding.copy
+        {
+            self.push(
+                FindingKind::Move,
+                ident.span(),
+                name.clone(),
+                format!("`{name}`의 소유권(ownership)이 이동합니다(move). Copy 타입이 아니면 이후에 `{name}`를 쓸 수 없습니다."),
+            );
+        }
+    }
+
+    // Copy 변수를 그대로 복사한 값도 Copy입니다.
+    fn is_copy_binding(&self, expr: &Expr) -> bool {
+        let Expr::Path(path) = expr else {
+            return false;
+        };
+        path.path
+            .get_ident()
+            .and_then(|ident| self.lookup(&ident.to_string()))
+            .is_some_and(|binding| binding.copy)
+    }
+
+    fn in_scope(&mut self, body: impl FnOnce(&mut Self)) {
+        self.scopes.push(HashMap::new());
+        body(self);
+        self.scopes.pop();
+    }
+}
+
+impl<'ast> Visit<'ast> for Analyzer<'_> {
+    fn visit_item_fn(&mut self, item: &'ast syn::ItemFn) {
+        self.in_scope(|analyzer| {
+            for input in &item.sig.inputs {
+                if let FnArg::Typed(arg) = input {
+                    analyzer.bind_pattern(&arg.pat, is_copy_type(&arg.ty), false);
+                }
+            }
+            analyzer.visit_block(&item.block);
+        });
+    }
+
+    fn visit_expr_closure(&mut self, closure: &'ast syn::ExprClosure) {
+        self.in_scope(|analyzer| {
+            for input in &closure.inputs {
+                analyzer.bind_pattern(input, false, false);
+            }
+            analyzer.visit_expr(&closure.body);
+        });
+    }
+
+    fn visit_block(&mut self, block: &'ast syn::Block) {
+        self.in_scope(|analyzer| visit::visit_block(analyzer, block));
+    }
+
+    fn visit_local(&mut self, local: &'ast syn::Local) {
+        // 초기값을 먼저 봐야 `let x = x + 1;`의 오른쪽 x가 이전 x를 가리킵니다.
+        let mut copy = false;
+        if let Some(init) = &local.init {
+            self.check_move(&init.expr);
+            self.visit_expr(&init.expr);
+            if let Some((_, diverge)) = &init.diverge {
+                self.visit_expr(diverge);
+            }
+            copy = is_copy_init(&init.expr) || self.is_copy_binding(&init.expr);
+        }
+        self.bind_pattern(&local.pat, copy, true);
+    }
+
+    fn visit_expr_reference(&mut self, reference: &'ast syn::ExprReference) {
+        let target = self.source_text(reference.expr.span());
+        if reference.mutability.is_some() {
+            self.push(
+                FindingKind::MutableBorrow,
+                reference.span(),
+                target.clone(),
+                format!("`{target}`를 가변 참조(&mut)로 빌립니다. 빌리는 동안 다른 참조를 만들 수 없습니다."),
+            );
+        } else {
+            self.push(
+                FindingKind::SharedBorrow,
+                reference.span(),
+                target.clone(),
+                format!("`{target}`를 불변 참조(&)로 빌립니다. 여러 개가 동시에 있어도 안전합니다(no data race)."),
+            );
+        }
+        visit::visit_expr_reference(self, reference);
+    }
+
+    fn visit_expr_call(&mut self, call: &'ast syn::ExprCall) {
+        for arg in &call.args {
+            self.check_move(arg);
+        }
+        visit::visit_expr_call(self, call);
+    }
+
+    fn visit_expr_method_call(&mut self, call: &'ast syn::ExprMethodCall) {
+        for arg in &call.args {
+            self.check_move(arg);
+        }
+        visit::visit_expr_method_call(self, call);
+    }
+
+    fn visit_expr_assign(&mut self, assign: &'ast syn::ExprAssign) {
+        self.check_move(&assign.right);
+        visit::visit_expr_assign(self, assign);
+    }
+
+    fn visit_expr_return(&mut self, ret: &'ast syn::ExprReturn) {
+        if let Some(expr) = &ret.expr {
+            self.check_move(expr);
+        }
+        visit::visit_expr_return(self, ret);
+    }
+
+    fn visit_expr_struct(&mut self, expr: &'ast syn::ExprStruct) {
+        for field in &expr.fields {
+            self.check_move(&field.expr);
+        }
+        visit::visit_expr_struct(self, expr);
+    }
+}
+
+// 정수, 실수, bool, char, 참조는 Copy입니다.
+fn is_copy_type(ty: &Type) -> bool {
+    match ty {
+        Type::Reference(reference) => reference.mutability.is_none(),
+        Type::Path(path) => path.path.get_ident().is_some_and(|ident| {
+            matches!(
+                ident.to_string().as_str(),
+                "i8" | "i16"
+                    | "i32"
+                    | "i64"
+                    | "i128"
+                    | "isize"
+                    | "u8"
+                    | "u16"
+                    | "u32"
+                    | "u64"
+                    | "u128"
+                    | "usize"
+                    | "f32"
+                    | "f64"
+                    | "bool"
+                    | "char"
+            )
+        }),
+        _ => false,
+    }
+}
+
+// 타입 표기가 없을 때 초기값으로 Copy인지 짐작합니다.
+fn is_copy_init(expr: &Expr) -> bool {
+    match expr {
+        Expr::Lit(lit) => matches!(
+            lit.lit,
+            Lit::Int(_) | Lit::Float(_) | Lit::Bool(_) | Lit::Char(_) | Lit::Byte(_)
+        ),
+        Expr::Unary(unary) => matches!(unary.op, UnOp::Neg(_)) && is_copy_init(&unary.expr),
+        Expr::Reference(reference) => reference.mutability.is_none(),
+        Expr::Paren(paren) => is_copy_init(&paren.expr),
+        _ => false,
+    }
+}
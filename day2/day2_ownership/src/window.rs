@@ -0,0 +1,97 @@
+use std::ops::RangeInclusive;
+
+// summarize_slice가 앞 3개만 보여 준다면, BlockWindow는 블록 높이 목록을 원하는 만큼 잘라 봅니다.
+// 모든 결과는 입력 슬라이스를 가리키는 서브 슬라이스이고 수명 'a가 입력에 묶여 있어, 복사나 할당이 없습니다.
+// 높이 목록은 오름차순(중복과 빈 구간은 허용)이라고 가정합니다. range와 gaps는 이 가정에 기댑니다.
+// A zero-copy view over an ascending list of block heights.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockWindow<'a> {
+    heights: &'a [u64],
+}
+
+// 한 페이지와 다음 페이지를 가져올 커서입니다. next_cursor가 None이면 마지막 페이지입니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Page<'a> {
+    pub items: &'a [u64],
+    pub next_cursor: Option<usize>,
+}
+
+// 연속한 두 높이 사이에 빠진 높이들. last_before는 빈 구간 바로 앞의 높이
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeightGap {
+    pub last_before: u64,
+    pub missing: RangeInclusive<u64>,
+}
+
+impl<'a> BlockWindow<'a> {
+    pub fn new(heights: &'a [u64]) -> Self {
+        Self { heights }
+    }
+
+    pub fn len(&self) -> usize {
+        self.heights.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.heights.is_empty()
+    }
+
+    // 앞에서 n개 (모자라면 가능한 만큼)
+    pub fn first(&self, n: usize) -> &'a [u64] {
+        &self.heights[..n.min(self.heights.len())]
+    }
+
+    // 뒤에서 n개. 가장 최근 블록들을 볼 때 씁니다.
+    pub fn last(&self, n: usize) -> &'a [u64] {
+        &self.heights[self.heights.len().saturating_sub(n)..]
+    }
+
+    // cursor 위치부터 size개. 처음에는 cursor 0으로 부르고, 이후에는 돌려받은 next_cursor를 넘깁니다.
+    pub fn page(&self, cursor: usize, size: usize) -> Page<'a> {
+        let start = cursor.min(self.heights.len());
+        let end = start.saturating_add(size).min(self.heights.len());
+        Page {
+            items: &self.heights[start..end],
+            next_cursor: (end < self.heights.len()).then_some(end),
+        }
+    }
+
+    // size개씩 끊은 모든 페이지. size가 0이면 1로 봅니다.
+    pub fn pages(&self, size: usize) -> std::slice::Chunks<'a, u64> {
+        self.heights.chunks(size.max(1))
+    }
+
+    // 높이가 range 안에 있는 블록들. 오름차순이므로 이진 탐색으로 양 끝을 찾습니다.
+    pub fn range(&self, range: RangeInclusive<u64>) -> &'a [u64] {
+        let start = self
+            .heights
+            .partition_point(|&height| height < *range.start());
+        let end = self
+            .heights
+            .partition_point(|&height| height <= *range.end());
+        &self.heights[start..end.max(start)]
+    }
+
+    // 오름차순(같은 값 허용)인지. 아니면 range와 gaps 결과를 믿을 수 없습니다.
+    pub fn is_ascending(&self) -> bool {
+        self.heights.is_sorted()
+    }
+
+    // 이웃한 두 높이가 1보다 많이 벌어진 곳마다 빠진 높이 구간을 돌려줍니다.
+    pub fn gaps(&self) -> impl Iterator<Item = HeightGap> + 'a {
+        self.heights.windows(2).filter_map(|pair| {
+            let (before, after) = (pair[0], pair[1]);
+            (after > before.saturating_add(1)).then(|| HeightGap {
+                last_before: before,
+                missing: before + 1..=after - 1,
+            })
+        })
+    }
+
+    // 같은 높이가 두 번 이상 연달아 나온 구간들. 각 구간도 입력의 서브 슬라이스입니다.
+    pub fn duplicates(&self) -> impl Iterator<Item = &'a [u64]> + 'a {
+        self.heights
+            .chunk_by(|a, b| a == b)
+            .filter(|run| run.len() > 1)
+    }
+}
@@ -0,0 +1,118 @@
+use day2_ownership::*;
+use proptest::prelude::*;
+
+// 끝값 근처를 자주 뽑도록 경계값과 무작위 값을 섞습니다.
+fn extreme_i64() -> impl Strategy<Value = i64> {
+    prop_oneof![
+        Just(i64::MIN),
+        Just(i64::MIN + 1),
+        Just(-1i64),
+        Just(0i64),
+        Just(1i64),
+        Just(i64::MAX - 1),
+        Just(i64::MAX),
+        any::<i64>(),
+    ]
+}
+
+fn extreme_u64() -> impl Strategy<Value = u64> {
+    prop_oneof![
+        Just(0u64),
+        Just(1u64),
+        Just(i64::MAX as u64),
+        Just(u64::MAX - 1),
+        Just(u64::MAX),
+        any::<u64>(),
+    ]
+}
+
+#[test]
+fn test_mutate_wallet_reports_overflow_instead_of_panicking() {
+    // i64::MAX 근처에서도 panic 없이 에러를 돌려주고 잔액은 그대로여야 함
+    let mut balance = i64::MAX - 1;
+    let result = mutate_wallet(&mut balance, 2);
+    assert!(result.unwrap_err().contains("범위를 벗어납니다"));
+    assert_eq!(balance, i64::MAX - 1, "원본 잔액이 변경되지 않아야 합니다");
+
+    assert_eq!(mutate_wallet(&mut balance, 1), Ok(()));
+    assert_eq!(balance, i64::MAX);
+}
+
+#[test]
+fn test_try_mutate_wallet_returns_typed_errors() {
+    // Balance 경로는 음수와 넘침을 서로 다른 에러로 구분해야 함
+    let mut balance = Balance::new(50);
+    assert_eq!(
+        try_mutate_wallet(&mut balance, -100),
+        Err(BalanceError::Negative {
+            balance: 50,
+            delta: -100
+        })
+    );
+    assert_eq!(
+        balance,
+        Balance::new(50),
+        "원본 잔액이 변경되지 않아야 합니다"
+    );
+
+    let mut balance = Balance::MAX;
+    assert_eq!(
+        try_mutate_wallet(&mut balance, 1),
+        Err(BalanceError::Overflow {
+            balance: u64::MAX,
+            delta: 1
+        })
+    );
+    assert_eq!(try_mutate_wallet(&mut balance, i64::MIN), Ok(()));
+    assert_eq!(balance.lamports(), u64::MAX - i64::MIN.unsigned_abs());
+
+    // 에러 메시지는 mutate_wallet과 같은 형식
+    let error = Balance::new(50).checked_apply(-100).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "잔액은 음수가 될 수 없습니다. 현재: 50, 변경: -100"
+    );
+}
+
+#[test]
+fn test_balance_saturating_operations() {
+    // saturating 연산은 0과 u64::MAX 사이에서 멈춰야 함
+    assert_eq!(Balance::new(5).saturating_apply(-10), Balance::ZERO);
+    assert_eq!(Balance::MAX.saturating_apply(i64::MAX), Balance::MAX);
+    assert_eq!(Balance::new(5).checked_sub(6), None);
+    assert_eq!(Balance::MAX.checked_add(1), None);
+    assert_eq!(Balance::new(5).checked_add(1), Some(Balance::new(6)));
+}
+
+proptest! {
+    #[test]
+    fn test_mutate_wallet_never_panics(start in extreme_i64(), delta in extreme_i64()) {
+        // 어떤 값이든 panic 없이, 성공하면 정확한 합이고 실패하면 잔액이 그대로여야 함
+        let mut balance = start;
+        let exact = start as i128 + delta as i128;
+        match mutate_wallet(&mut balance, delta) {
+            Ok(()) => {
+                prop_assert!(exact >= 0);
+                prop_assert_eq!(balance as i128, exact);
+            }
+            Err(_) => {
+                prop_assert!(exact < 0 || exact > i64::MAX as i128);
+                prop_assert_eq!(balance, start);
+            }
+        }
+    }
+
+    #[test]
+    fn test_balance_matches_wide_arithmetic(start in extreme_u64(), delta in extreme_i64()) {
+        // i128로 계산한 정답과 checked/saturating 결과가 일치해야 함
+        let exact = start as i128 + delta as i128;
+        let balance = Balance::new(start);
+        match balance.checked_apply(delta) {
+            Ok(next) => prop_assert_eq!(next.lamports() as i128, exact),
+            Err(BalanceError::Negative { .. }) => prop_assert!(exact < 0),
+            Err(BalanceError::Overflow { .. }) => prop_assert!(exact > u64::MAX as i128),
+        }
+        let saturated = exact.clamp(0, u64::MAX as i128) as u64;
+        prop_assert_eq!(balance.saturating_apply(delta).lamports(), saturated);
+    }
+}
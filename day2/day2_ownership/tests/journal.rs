@@ -0,0 +1,156 @@
+use day2_ownership::*;
+use proptest::prelude::*;
+
+// 무작위로 만들 잔액 변경. 계정 번호를 좁게 잡아 같은 계정이 자주 겹치게 합니다.
+#[derive(Debug, Clone)]
+enum Op {
+    Open(u8),
+    Deposit(u8, u64),
+    Withdraw(u8, u64),
+    Transfer(u8, u8, u64),
+    Batch(Vec<(u8, u8, u64)>),
+}
+
+fn amount() -> impl Strategy<Value = u64> {
+    // 작은 값 위주로, 가끔 넘침을 일으키는 큰 값
+    prop_oneof![4 => 0..500u64, 1 => any::<u64>()]
+}
+
+fn op() -> impl Strategy<Value = Op> {
+    let account = 0..4u8;
+    prop_oneof![
+        account.clone().prop_map(Op::Open),
+        (account.clone(), amount()).prop_map(|(a, n)| Op::Deposit(a, n)),
+        (account.clone(), amount()).prop_map(|(a, n)| Op::Withdraw(a, n)),
+        (account.clone(), account.clone(), amount()).prop_map(|(a, b, n)| Op::Transfer(a, b, n)),
+        prop::collection::vec((account.clone(), account, amount()), 0..4).prop_map(Op::Batch),
+    ]
+}
+
+fn ops() -> impl Strategy<Value = Vec<Op>> {
+    prop::collection::vec(op(), 0..24)
+}
+
+// 실패한 변경은 그냥 무시합니다. 실패해도 상태가 바뀌지 않아야 하는 것까지 함께 검사됩니다.
+fn apply(state: &mut JournaledLedger<u8>, ops: &[Op]) {
+    for op in ops {
+        match op {
+            Op::Open(account) => {
+                state.open_account(*account);
+            }
+            Op::Deposit(account, amount) => {
+                let _ = state.deposit(account, *amount);
+            }
+            Op::Withdraw(account, amount) => {
+                let _ = state.withdraw(account, *amount);
+            }
+            Op::Transfer(from, to, amount) => {
+                let _ = state.transfer(from, to, *amount);
+            }
+            Op::Batch(transfers) => {
+                let transfers: Vec<Transfer<u8>> = transfers
+                    .iter()
+                    .map(|&(from, to, amount)| Transfer { from, to, amount })
+                    .collect();
+                let _ = state.apply_batch(&transfers);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_journal_nested_checkpoints() {
+    // 안쪽 체크포인트를 확정해도 바깥 체크포인트로 되돌리면 모두 취소되어야 함
+    let mut state = JournaledLedger::new(Ledger::new());
+    state.open_account("alice".to_string());
+    state.deposit(&"alice".to_string(), 100).unwrap();
+
+    let outer = state.checkpoint();
+    state.open_account("bob".to_string());
+    state
+        .transfer(&"alice".to_string(), &"bob".to_string(), 30)
+        .unwrap();
+
+    let inner = state.checkpoint();
+    state.withdraw(&"bob".to_string(), 10).unwrap();
+    assert_eq!(state.depth(), 2);
+    assert_eq!(state.commit(inner), Ok(()));
+    assert_eq!(state.ledger().balance(&"bob".to_string()), Some(20));
+
+    assert_eq!(state.revert_to(outer), Ok(()));
+    assert_eq!(state.ledger().balance(&"alice".to_string()), Some(100));
+    assert_eq!(
+        state.ledger().balance(&"bob".to_string()),
+        None,
+        "되돌리면 연 계정도 사라져야 합니다"
+    );
+    assert_eq!(state.depth(), 0);
+
+    // 닫힌 체크포인트는 다시 쓸 수 없음
+    assert_eq!(state.revert_to(inner), Err(JournalError::UnknownCheckpoint));
+    let _reopened = state.checkpoint();
+    assert_eq!(state.commit(outer), Err(JournalError::UnknownCheckpoint));
+}
+
+proptest! {
+    #[test]
+    fn test_revert_restores_exact_state(setup in ops(), changes in ops()) {
+        // 체크포인트 이후의 어떤 변경이든 되돌리면 체크포인트 시점과 같아야 함
+        let mut state = JournaledLedger::new(Ledger::new());
+        apply(&mut state, &setup);
+        let before = state.ledger().clone();
+
+        let checkpoint = state.checkpoint();
+        apply(&mut state, &changes);
+        state.revert_to(checkpoint).unwrap();
+        prop_assert_eq!(state.ledger(), &before);
+    }
+
+    #[test]
+    fn test_nested_reverts_restore_each_level(
+        first in ops(),
+        second in ops(),
+        third in ops(),
+        commit_inner in any::<bool>(),
+    ) {
+        // 안쪽부터 하나씩 되돌리면 각 체크포인트 시점으로 정확히 돌아가야 함
+        let mut state = JournaledLedger::new(Ledger::new());
+        let outer = state.checkpoint();
+        apply(&mut state, &first);
+        let after_first = state.ledger().clone();
+
+        let inner = state.checkpoint();
+        apply(&mut state, &second);
+        let after_second = state.ledger().clone();
+
+        let innermost = state.checkpoint();
+        apply(&mut state, &third);
+        if commit_inner {
+            state.commit(innermost).unwrap();
+        } else {
+            state.revert_to(innermost).unwrap();
+            prop_assert_eq!(state.ledger(), &after_second);
+        }
+
+        state.revert_to(inner).unwrap();
+        prop_assert_eq!(state.ledger(), &after_first);
+        state.revert_to(outer).unwrap();
+        prop_assert!(state.ledger().is_empty());
+    }
+
+    #[test]
+    fn test_commit_keeps_changes(setup in ops(), changes in ops()) {
+        // 확정은 체크포인트 없이 적용한 것과 같은 결과여야 함
+        let mut journaled = JournaledLedger::new(Ledger::new());
+        let mut plain = JournaledLedger::new(Ledger::new());
+        apply(&mut journaled, &setup);
+        apply(&mut plain, &setup);
+
+        let checkpoint = journaled.checkpoint();
+        apply(&mut journaled, &changes);
+        apply(&mut plain, &changes);
+        journaled.commit(checkpoint).unwrap();
+        prop_assert_eq!(journaled.ledger(), plain.ledger());
+        prop_assert_eq!(journaled.ledger().total(), plain.ledger().total());
+    }
+}
@@ -0,0 +1,119 @@
+use day2_ownership::*;
+
+fn ledger(balances: &[(&str, u64)]) -> Ledger<String> {
+    let mut ledger = Ledger::new();
+    for &(account, balance) in balances {
+        ledger.open_account(account.to_string());
+        ledger.deposit(&account.to_string(), balance).unwrap();
+    }
+    ledger
+}
+
+fn key(account: &str) -> String {
+    account.to_string()
+}
+
+#[test]
+fn test_ledger_deposit_withdraw_and_affordability() {
+    // 계정별 입출금과 수수료 지불 가능 여부 확인
+    let mut ledger = ledger(&[("alice", 100)]);
+    assert!(
+        !ledger.open_account(key("alice")),
+        "이미 있는 계정은 다시 열리지 않아야 합니다"
+    );
+    assert_eq!(ledger.balance(&key("alice")), Some(100));
+
+    assert_eq!(ledger.withdraw(&key("alice"), 40), Ok(60));
+    assert!(ledger.can_afford(&key("alice"), 60));
+    assert!(!ledger.can_afford(&key("alice"), 61));
+    assert!(
+        !ledger.can_afford(&key("nobody"), 0),
+        "모르는 계정은 지불할 수 없습니다"
+    );
+
+    // 잔액보다 많이 빼면 음수 대신 타입이 있는 에러
+    assert_eq!(
+        ledger.withdraw(&key("alice"), 61),
+        Err(LedgerError::InsufficientFunds {
+            account: key("alice"),
+            balance: 60,
+            amount: 61
+        })
+    );
+    assert_eq!(
+        ledger.deposit(&key("alice"), u64::MAX),
+        Err(LedgerError::Overflow {
+            account: key("alice"),
+            balance: 60,
+            amount: u64::MAX
+        })
+    );
+    assert_eq!(
+        ledger.balance(&key("alice")),
+        Some(60),
+        "실패한 작업은 잔액을 바꾸지 않아야 합니다"
+    );
+}
+
+#[test]
+fn test_ledger_transfer_is_atomic() {
+    // 이체는 양쪽을 모두 검사한 뒤에만 적용되어야 함
+    let mut ledger = ledger(&[("alice", 100), ("bob", u64::MAX - 10)]);
+
+    assert_eq!(ledger.transfer(&key("alice"), &key("bob"), 10), Ok(()));
+    assert_eq!(ledger.balance(&key("alice")), Some(90));
+    assert_eq!(ledger.balance(&key("bob")), Some(u64::MAX));
+
+    // 받는 쪽이 넘치면 보내는 쪽도 그대로
+    assert!(matches!(
+        ledger.transfer(&key("alice"), &key("bob"), 1),
+        Err(LedgerError::Overflow { .. })
+    ));
+    assert_eq!(ledger.balance(&key("alice")), Some(90));
+
+    assert_eq!(
+        ledger.transfer(&key("alice"), &key("carol"), 1),
+        Err(LedgerError::UnknownAccount {
+            account: key("carol")
+        })
+    );
+    assert_eq!(ledger.balance(&key("alice")), Some(90));
+
+    // 자기 자신에게 보내도 잔액은 그대로지만, 잔액 검사는 합니다
+    assert_eq!(ledger.transfer(&key("alice"), &key("alice"), 90), Ok(()));
+    assert!(ledger.transfer(&key("alice"), &key("alice"), 91).is_err());
+    assert_eq!(ledger.balance(&key("alice")), Some(90));
+}
+
+#[test]
+fn test_ledger_batch_applies_all_or_nothing() {
+    // 배치는 앞선 이체의 결과를 이어받고, 하나라도 실패하면 전부 취소됨
+    let mut ledger = ledger(&[("alice", 100), ("bob", 0), ("carol", 0)]);
+    let transfer = |from: &str, to: &str, amount| Transfer {
+        from: key(from),
+        to: key(to),
+        amount,
+    };
+
+    // bob은 처음에 0이지만 앞의 이체로 받은 돈을 다시 보낼 수 있음
+    let batch = [transfer("alice", "bob", 70), transfer("bob", "carol", 50)];
+    assert_eq!(ledger.apply_batch(&batch), Ok(()));
+    assert_eq!(ledger.balance(&key("alice")), Some(30));
+    assert_eq!(ledger.balance(&key("bob")), Some(20));
+    assert_eq!(ledger.balance(&key("carol")), Some(50));
+
+    let before = ledger.clone();
+    let batch = [transfer("carol", "alice", 50), transfer("bob", "alice", 21)];
+    let error = ledger.apply_batch(&batch).unwrap_err();
+    assert_eq!(error.index, 1);
+    assert!(matches!(error.error, LedgerError::InsufficientFunds { .. }));
+    assert_eq!(
+        ledger, before,
+        "실패한 배치는 아무것도 바꾸지 않아야 합니다"
+    );
+    assert_eq!(
+        ledger.total(),
+        100,
+        "이체는 전체 잔액을 바꾸지 않아야 합니다"
+    );
+}
@@ -0,0 +1,98 @@
+use day2_ownership::*;
+
+// (종류, 줄, 대상)만 뽑아 비교합니다.
+fn summary(report: &LintReport) -> Vec<(FindingKind, usize, &str)> {
+    report
+        .findings
+        .iter()
+        .map(|finding| (finding.kind, finding.line, finding.subject.as_str()))
+        .collect()
+}
+
+#[test]
+fn test_analyze_bindings_and_shadowing() {
+    // 함수 본문 조각에서 let / let mut / 섀도잉을 줄 번호와 함께 찾아야 함
+    let snippet = "let x = 5;\nlet mut y = 10;\ny = 11;\nlet x = \"five\";";
+    let report = analyze_snippet(snippet).unwrap();
+
+    assert_eq!(
+        summary(&report),
+        vec![
+            (FindingKind::ImmutableBinding, 1, "x"),
+            (FindingKind::MutableBinding, 2, "y"),
+            (FindingKind::Shadowing, 4, "x"),
+            (FindingKind::ImmutableBinding, 4, "x"),
+        ]
+    );
+    let shadowing = report.of_kind(FindingKind::Shadowing).next().unwrap();
+    assert!(
+        shadowing.explanation.contains("1번째 줄"),
+        "가려진 변수의 줄을 알려 줘야 합니다"
+    );
+    // 첫 줄 칸 번호는 감싼 머리말과 상관없이 원문 기준
+    assert_eq!(report.findings[0].column, 5);
+}
+
+#[test]
+fn test_analyze_moves_and_borrows() {
+    // String은 이동으로, 정수는 복사로 보고, &와 &mut 빌림을 찾아야 함
+    let snippet = r#"
+fn main() {
+    let count = 3;
+    let copied = count;
+    let name = String::from("node");
+    let owner = name;
+    let mut blocks = vec![1, 2];
+    push_block(&mut blocks, copied);
+    print_name(&owner);
+    consume(owner);
+}
+"#;
+    let report = analyze_snippet(snippet).unwrap();
+
+    let moves: Vec<(usize, &str)> = report
+        .of_kind(FindingKind::Move)
+        .map(|finding| (finding.line, finding.subject.as_str()))
+        .collect();
+    assert_eq!(moves, vec![(6, "name"), (10, "owner")]);
+
+    let borrows: Vec<(FindingKind, usize, &str)> = summary(&report)
+        .into_iter()
+        .filter(|(kind, _, _)| {
+            matches!(kind, FindingKind::SharedBorrow | FindingKind::MutableBorrow)
+        })
+        .collect();
+    assert_eq!(
+        borrows,
+        vec![
+            (FindingKind::MutableBorrow, 8, "blocks"),
+            (FindingKind::SharedBorrow, 9, "owner"),
+        ]
+    );
+    assert_eq!(report.of_kind(FindingKind::MutableBinding).count(), 1);
+}
+
+#[test]
+fn test_analyze_scopes_and_report_text() {
+    // 블록 안에서 같은 이름을 다시 쓰면 섀도잉이고, 블록이 끝나면 바깥 변수만 남아야 함
+    let snippet = "let total = 1;\n{\n    let total = total + 1;\n}\nlet next = total;";
+    let report = analyze_snippet(snippet).unwrap();
+    assert_eq!(report.of_kind(FindingKind::Shadowing).count(), 1);
+    assert_eq!(
+        report.of_kind(FindingKind::Move).count(),
+        0,
+        "정수는 이동하지 않아야 합니다"
+    );
+
+    let text = report.to_string();
+    assert!(text.starts_with("1:5 [let] `total`는 불변(immutable) 바인딩입니다."));
+    assert_eq!(text.lines().count(), report.findings.len());
+}
+
+#[test]
+fn test_analyze_reports_parse_errors() {
+    // 문법 오류는 위치와 함께 에러로 돌려줘야 함
+    let error = analyze_snippet("let x = ;\nlet y = 2;").unwrap_err();
+    assert_eq!(error.line, 1);
+    assert!(error.to_string().contains("파싱할 수 없습니다"));
+}
@@ -0,0 +1,98 @@
+use std::ops::RangeInclusive;
+
+use day2_ownership::*;
+
+#[test]
+fn test_block_window_first_and_last() {
+    // 앞/뒤 n개가 입력과 같은 메모리를 가리키는지 확인
+    let blocks = [100, 101, 102, 103, 104];
+    let window = BlockWindow::new(&blocks);
+
+    assert_eq!(
+        window.first(3),
+        summarize_slice(&blocks).0,
+        "summarize_slice와 같은 앞부분이어야 합니다"
+    );
+    assert_eq!(window.last(2), &[103, 104]);
+    assert_eq!(
+        window.last(10),
+        &blocks,
+        "모자라면 가능한 만큼 반환해야 합니다"
+    );
+    assert!(
+        std::ptr::eq(window.last(2).as_ptr(), &blocks[3]),
+        "복사 없이 원본을 가리켜야 합니다"
+    );
+    assert_eq!(BlockWindow::new(&[]).last(3), &[] as &[u64]);
+}
+
+#[test]
+fn test_block_window_paging_with_cursor() {
+    // 커서를 따라가면 모든 블록을 한 번씩 보고 끝나야 함
+    let blocks: Vec<u64> = (1..=7).collect();
+    let window = BlockWindow::new(&blocks);
+
+    let mut cursor = Some(0);
+    let mut seen = Vec::new();
+    while let Some(position) = cursor {
+        let page = window.page(position, 3);
+        seen.push(page.items);
+        cursor = page.next_cursor;
+    }
+    assert_eq!(seen, vec![&[1, 2, 3][..], &[4, 5, 6], &[7]]);
+    assert_eq!(
+        window.pages(3).collect::<Vec<_>>(),
+        seen,
+        "pages와 커서 결과가 같아야 합니다"
+    );
+
+    // 끝을 넘는 커서는 빈 마지막 페이지
+    assert_eq!(
+        window.page(99, 3),
+        Page {
+            items: &[],
+            next_cursor: None
+        }
+    );
+}
+
+#[test]
+fn test_block_window_range_by_height() {
+    // 높이 범위는 인덱스가 아니라 값으로 잘라야 함
+    let blocks = [100, 102, 102, 105, 109, 110];
+    let window = BlockWindow::new(&blocks);
+
+    assert_eq!(window.range(101..=105), &[102, 102, 105]);
+    assert_eq!(window.range(0..=99), &[] as &[u64]);
+    assert_eq!(window.range(110..=u64::MAX), &[110]);
+    assert_eq!(
+        window.range(RangeInclusive::new(105, 101)),
+        &[] as &[u64],
+        "뒤집힌 범위는 비어 있어야 합니다"
+    );
+}
+
+#[test]
+fn test_block_window_gaps_and_duplicates() {
+    // 빠진 높이와 중복된 높이를 찾아야 함
+    let blocks = [100, 101, 101, 101, 104, 105, 105, 106];
+    let window = BlockWindow::new(&blocks);
+
+    assert_eq!(
+        window.gaps().collect::<Vec<_>>(),
+        vec![HeightGap {
+            last_before: 101,
+            missing: 102..=103
+        }]
+    );
+    assert_eq!(
+        window.duplicates().collect::<Vec<_>>(),
+        vec![&[101, 101, 101][..], &[105, 105]]
+    );
+    assert!(window.is_ascending());
+    assert!(!BlockWindow::new(&[3, 2, 1]).is_ascending());
+
+    let contiguous = [7, 8, 9];
+    assert_eq!(BlockWindow::new(&contiguous).gaps().count(), 0);
+    assert_eq!(BlockWindow::new(&contiguous).duplicates().count(), 0);
+}
@@ -0,0 +1,126 @@
+// 슬롯 조회가 실패했을 때 무엇을 돌려줄지 정하는 전략입니다.
+// How a failed slot lookup is handled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FallbackStrategy {
+    // 마지막으로 성공한 슬롯을 대신 씁니다. 아직 성공한 적이 없으면 오류입니다.
+    LastKnownGood,
+    // 호출자가 정한 값을 대신 씁니다.
+    Default(u64),
+    // 대신할 값 없이 오류를 그대로 돌려줍니다.
+    Propagate,
+}
+
+// 돌려준 슬롯이 실제 조회 결과인지, 실패해서 대신 넣은 값인지 구분합니다.
+// "슬롯 0"과 "조회 실패"를 더는 헷갈리지 않도록 대체 사유를 함께 들고 다닙니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SlotValue {
+    Real(u64),
+    Substituted { value: u64, reason: String },
+}
+
+impl SlotValue {
+    pub fn value(&self) -> u64 {
+        match self {
+            SlotValue::Real(value) | SlotValue::Substituted { value, .. } => *value,
+        }
+    }
+
+    pub fn is_substituted(&self) -> bool {
+        matches!(self, SlotValue::Substituted { .. })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum FallbackError {
+    #[error("slot lookup failed: {reason}")]
+    LookupFailed { reason: String },
+    #[error("slot lookup failed before any slot was known: {reason}")]
+    NoLastKnownGood { reason: String },
+}
+
+// 훅에 넘기는 대체 기록입니다. substituted가 None이면 대신할 값이 없어 오류를 돌려준 경우입니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FallbackEvent<'a> {
+    pub reason: &'a str,
+    pub substituted: Option<u64>,
+}
+
+type FallbackHook = Box<dyn FnMut(FallbackEvent<'_>)>;
+
+// 전략에 따라 조회 결과를 SlotValue로 바꾸고, 실패할 때마다 횟수를 세고 훅을 부릅니다.
+// Resolves slot lookups with a strategy and records every failure.
+pub struct SlotFallback {
+    strategy: FallbackStrategy,
+    last_good: Option<u64>,
+    failures: u64,
+    last_reason: Option<String>,
+    hook: Option<FallbackHook>,
+}
+
+impl SlotFallback {
+    pub fn new(strategy: FallbackStrategy) -> Self {
+        Self {
+            strategy,
+            last_good: None,
+            failures: 0,
+            last_reason: None,
+            hook: None,
+        }
+    }
+
+    // 실패할 때마다 불릴 훅을 붙입니다. 로그나 지표 수집에 씁니다.
+    pub fn with_hook(mut self, hook: impl FnMut(FallbackEvent<'_>) + 'static) -> Self {
+        self.hook = Some(Box::new(hook));
+        self
+    }
+
+    pub fn strategy(&self) -> FallbackStrategy {
+        self.strategy
+    }
+
+    // 지금까지 성공한 마지막 슬롯
+    pub fn last_good(&self) -> Option<u64> {
+        self.last_good
+    }
+
+    // 조회가 실패한 횟수 (대체했든 오류를 돌려줬든 모두 셉니다)
+    pub fn failures(&self) -> u64 {
+        self.failures
+    }
+
+    pub fn last_reason(&self) -> Option<&str> {
+        self.last_reason.as_deref()
+    }
+
+    pub fn resolve(&mut self, slot: Result<u64, String>) -> Result<SlotValue, FallbackError> {
+        let reason = match slot {
+            Ok(value) => {
+                self.last_good = Some(value);
+                return Ok(SlotValue::Real(value));
+            }
+            Err(reason) => reason,
+        };
+
+        let substituted = match self.strategy {
+            FallbackStrategy::LastKnownGood => self.last_good,
+            FallbackStrategy::Default(value) => Some(value),
+            FallbackStrategy::Propagate => None,
+        };
+        self.failures += 1;
+        if let Some(hook) = self.hook.as_mut() {
+            hook(FallbackEvent {
+                reason: &reason,
+                substituted,
+            });
+        }
+        self.last_reason = Some(reason.clone());
+
+        match (substituted, self.strategy) {
+            (Some(value), _) => Ok(SlotValue::Substituted { value, reason }),
+            (None, FallbackStrategy::LastKnownGood) => {
+                Err(FallbackError::NoLastKnownGood { reason })
+            }
+            (None, _) => Err(FallbackError::LookupFailed { reason }),
+        }
+    }
+}
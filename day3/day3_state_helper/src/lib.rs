@@ -1,4 +1,13 @@
+mod fallback;
+mod progress;
+mod speed;
+
+pub use fallback::{FallbackError, FallbackEvent, FallbackStrategy, SlotFallback, SlotValue};
+pub use progress::{Clock, SyncProgress, SyncTracker, SystemClock, format_duration};
+pub use speed::{ClassifierError, Locale, SpeedClass, SpeedClassifier, WindowStat, window_latency};
+
 // 블록체인 동기화 높이를 사용자 친화적인 메시지로 변환합니다 / Convert blockchain sync height to user-friendly message
+// 목표 높이 대비 진행률과 남은 시간이 필요하면 SyncTracker를 쓰세요.
 pub fn describe_sync_height(height: Option<u64>) -> String {
     match height {
         Some(value) => format!("Current height: {value}"),
@@ -7,6 +16,9 @@ pub fn describe_sync_height(height: Option<u64>) -> String {
 }
 
 // 슬롯 조회 실패 시 안전한 기본값을 제공합니다 / Provide safe default value when slot lookup fails
+// 실패 사유를 버리므로 슬롯 0과 조회 실패를 구분할 수 없습니다. 구분이 필요하면 SlotFallback을 쓰세요.
+// 학습용으로 Ok/Err 분기를 그대로 보여 주는 match라 clippy의 unwrap_or 계열 제안은 따르지 않습니다.
+#[allow(clippy::manual_unwrap_or_default, clippy::manual_unwrap_or)]
 pub fn fallback_slot(slot: Result<u64, String>) -> u64 {
     match slot {
         Ok(value) => value,
@@ -15,10 +27,9 @@ pub fn fallback_slot(slot: Result<u64, String>) -> u64 {
 }
 
 // 피어의 응답 속도를 카테고리로 분류합니다 / Classify peer response speed into categories
+// 경계값이나 언어를 바꾸려면 SpeedClassifier와 SpeedClass::label을 직접 쓰세요.
 pub fn classify_peer_speed(ms: u64) -> &'static str {
-    match ms {
-        0..=150 => "Instant",
-        151..=400 => "Acceptable",
-        401.. => "Lagging",
-    }
+    SpeedClassifier::default()
+        .classify(ms)
+        .label(Locale::English)
 }
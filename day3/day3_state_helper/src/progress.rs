@@ -0,0 +1,189 @@
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// 현재 시각(ms)을 알려 주는 시계입니다. 테스트에서는 직접 움직이는 시계를 넣어 결과를 고정합니다.
+// Source of the current time, injectable so progress math is deterministic in tests.
+pub trait Clock {
+    fn now_ms(&self) -> u64;
+}
+
+// 시스템 시계 (유닉스 시간 기준 ms)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now_ms(&self) -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_millis() as u64)
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for &C {
+    fn now_ms(&self) -> u64 {
+        (**self).now_ms()
+    }
+}
+
+// 블록/초 지수 이동 평균의 기본 가중치. 클수록 최근 속도에 빨리 반응합니다.
+const DEFAULT_RATE_ALPHA: f64 = 0.3;
+
+// 높이 샘플과 목표 높이로 계산한 동기화 진행 상황입니다.
+// 문자열 메시지가 필요하면 Display(to_string)를 쓰세요.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SyncProgress {
+    pub current_height: Option<u64>,
+    pub target_height: Option<u64>,
+    // 추적을 시작한 높이부터 목표까지 중 얼마나 왔는지 (0~100)
+    pub percent: Option<f64>,
+    // 평활화한 블록/초. 샘플이 두 개 이상이어야 계산됩니다.
+    pub blocks_per_sec: Option<f64>,
+    // 목표까지 남은 예상 시간(초). 속도가 0이거나 모르면 None입니다.
+    pub eta_secs: Option<u64>,
+}
+
+impl SyncProgress {
+    // 목표 높이를 따라잡았는지
+    pub fn is_synced(&self) -> bool {
+        matches!(
+            (self.current_height, self.target_height),
+            (Some(current), Some(target)) if current >= target
+        )
+    }
+}
+
+impl fmt::Display for SyncProgress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some(current) = self.current_height else {
+            return write!(f, "No blocks yet");
+        };
+        let Some(target) = self.target_height else {
+            return write!(f, "Current height: {current} (target unknown)");
+        };
+        if self.is_synced() {
+            return write!(f, "Synced at height {current}");
+        }
+        write!(
+            f,
+            "Syncing: {current} / {target} ({:.1}%)",
+            self.percent.unwrap_or(0.0)
+        )?;
+        match (self.blocks_per_sec, self.eta_secs) {
+            (Some(rate), Some(eta)) => {
+                write!(f, ", {rate:.1} blocks/s, ETA {}", format_duration(eta))
+            }
+            (Some(rate), None) => write!(f, ", {rate:.1} blocks/s, ETA unknown"),
+            (None, _) => write!(f, ", measuring speed"),
+        }
+    }
+}
+
+// 초를 "1h 02m 03s" / "4m 05s" / "9s" 형태로 바꿉니다.
+pub fn format_duration(secs: u64) -> String {
+    let (hours, minutes, seconds) = (secs / 3600, secs % 3600 / 60, secs % 60);
+    if hours > 0 {
+        format!("{hours}h {minutes:02}m {seconds:02}s")
+    } else if minutes > 0 {
+        format!("{minutes}m {seconds:02}s")
+    } else {
+        format!("{seconds}s")
+    }
+}
+
+// 로컬 높이 샘플을 시간과 함께 모아 동기화 속도와 남은 시간을 추정합니다.
+// Tracks local height samples over time against a target height reported by peers.
+#[derive(Debug, Clone)]
+pub struct SyncTracker<C: Clock = SystemClock> {
+    clock: C,
+    rate_alpha: f64,
+    start_height: Option<u64>,
+    // 마지막 샘플 (시각 ms, 높이)
+    last_sample: Option<(u64, u64)>,
+    blocks_per_sec: Option<f64>,
+    target_height: Option<u64>,
+}
+
+impl SyncTracker<SystemClock> {
+    pub fn new() -> Self {
+        Self::with_clock(SystemClock)
+    }
+}
+
+impl Default for SyncTracker<SystemClock> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: Clock> SyncTracker<C> {
+    pub fn with_clock(clock: C) -> Self {
+        Self {
+            clock,
+            rate_alpha: DEFAULT_RATE_ALPHA,
+            start_height: None,
+            last_sample: None,
+            blocks_per_sec: None,
+            target_height: None,
+        }
+    }
+
+    // 속도 평활화 가중치(0보다 크고 1 이하)를 바꿉니다. 범위를 벗어나면 잘라 냅니다.
+    // NaN은 잘라 낼 수 없고 이후 속도와 ETA를 모두 NaN으로 만들므로, 무시하고 기존 값을 유지합니다.
+    pub fn with_rate_alpha(mut self, alpha: f64) -> Self {
+        if !alpha.is_nan() {
+            self.rate_alpha = alpha.clamp(f64::MIN_POSITIVE, 1.0);
+        }
+        self
+    }
+
+    // 피어들이 알려 준 목표 높이로 바꿉니다.
+    pub fn set_target(&mut self, target_height: u64) {
+        self.target_height = Some(target_height);
+    }
+
+    // 현재 시각의 로컬 높이를 기록합니다. 시간이 흐르지 않은 샘플은 높이만 갱신하고 속도 계산에서 뺍니다.
+    pub fn record_height(&mut self, height: u64) {
+        let now = self.clock.now_ms();
+        self.start_height.get_or_insert(height);
+        if let Some((last_ms, last_height)) = self.last_sample
+            && now > last_ms
+        {
+            let blocks = height.saturating_sub(last_height) as f64;
+            let rate = blocks * 1000.0 / (now - last_ms) as f64;
+            self.blocks_per_sec = Some(match self.blocks_per_sec {
+                Some(previous) => previous + self.rate_alpha * (rate - previous),
+                None => rate,
+            });
+        }
+        self.last_sample = Some((now, height));
+    }
+
+    pub fn progress(&self) -> SyncProgress {
+        let current_height = self.last_sample.map(|(_, height)| height);
+        let percent = match (self.start_height, current_height, self.target_height) {
+            (Some(start), Some(current), Some(target)) => Some(if current >= target {
+                100.0
+            } else if target <= start {
+                // 시작 뒤에 목표가 낮아진 경우: 거리 기준이 없으므로 0%로 봅니다.
+                0.0
+            } else {
+                current.saturating_sub(start) as f64 * 100.0 / (target - start) as f64
+            }),
+            _ => None,
+        };
+        let eta_secs = match (current_height, self.target_height, self.blocks_per_sec) {
+            (Some(current), Some(target), _) if current >= target => Some(0),
+            (Some(current), Some(target), Some(rate)) if rate > 0.0 => {
+                Some(((target - current) as f64 / rate).ceil() as u64)
+            }
+            _ => None,
+        };
+        SyncProgress {
+            current_height,
+            target_height: self.target_height,
+            percent,
+            blocks_per_sec: self.blocks_per_sec,
+            eta_secs,
+        }
+    }
+}
@@ -0,0 +1,148 @@
+// 피어 응답 속도 등급입니다. 분류기가 어떤 등급을 쓸지 고르므로 기본 3단계보다 더 잘게 나눌 수도 있습니다.
+// Peer speed tiers, fastest first. A classifier picks which of them it uses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SpeedClass {
+    Instant,
+    Fast,
+    Acceptable,
+    Slow,
+    Lagging,
+}
+
+// 대시보드 표시 언어
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Locale {
+    English,
+    Korean,
+}
+
+impl SpeedClass {
+    // 같은 등급을 한국어/영어 대시보드에서 각자의 말로 보여 줍니다.
+    pub fn label(&self, locale: Locale) -> &'static str {
+        match (locale, self) {
+            (Locale::English, SpeedClass::Instant) => "Instant",
+            (Locale::English, SpeedClass::Fast) => "Fast",
+            (Locale::English, SpeedClass::Acceptable) => "Acceptable",
+            (Locale::English, SpeedClass::Slow) => "Slow",
+            (Locale::English, SpeedClass::Lagging) => "Lagging",
+            (Locale::Korean, SpeedClass::Instant) => "즉시",
+            (Locale::Korean, SpeedClass::Fast) => "빠름",
+            (Locale::Korean, SpeedClass::Acceptable) => "양호",
+            (Locale::Korean, SpeedClass::Slow) => "느림",
+            (Locale::Korean, SpeedClass::Lagging) => "지연",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ClassifierError {
+    #[error("tier thresholds must be strictly increasing, but {next} ms follows {previous} ms")]
+    ThresholdsNotIncreasing { previous: u64, next: u64 },
+    #[error("tier classes must get slower with each tier, but {next:?} follows {previous:?}")]
+    ClassesNotSlower {
+        previous: SpeedClass,
+        next: SpeedClass,
+    },
+}
+
+// 창(window) 안의 여러 샘플을 하나의 대표 지연 시간으로 줄이는 방법입니다.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WindowStat {
+    // nearest-rank 백분위수(0~100). 예: 90이면 느린 쪽 10%만 무시합니다.
+    Percentile(f64),
+    // 평균 + 지터. 지터는 이웃한 샘플 간 차이의 평균으로, 들쭉날쭉한 피어를 한 단계 느리게 봅니다.
+    MeanPlusJitter,
+}
+
+// 경계값(ms, 포함)과 등급 목록으로 지연 시간을 분류합니다. 마지막 경계보다 느리면 slowest 등급입니다.
+// Classifies latencies with configurable, inclusive upper bounds per tier.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpeedClassifier {
+    tiers: Vec<(u64, SpeedClass)>,
+    slowest: SpeedClass,
+}
+
+impl Default for SpeedClassifier {
+    // classify_peer_speed와 같은 기본 경계: 0~150 Instant, 151~400 Acceptable, 그 이상 Lagging
+    fn default() -> Self {
+        Self {
+            tiers: vec![(150, SpeedClass::Instant), (400, SpeedClass::Acceptable)],
+            slowest: SpeedClass::Lagging,
+        }
+    }
+}
+
+impl SpeedClassifier {
+    // 경계값은 점점 커지고 등급은 점점 느려져야 합니다. 그렇지 않으면 구간이 겹치거나 뒤집힙니다.
+    pub fn new(
+        tiers: Vec<(u64, SpeedClass)>,
+        slowest: SpeedClass,
+    ) -> Result<Self, ClassifierError> {
+        for pair in tiers.windows(2) {
+            let ((previous_ms, previous_class), (next_ms, next_class)) = (pair[0], pair[1]);
+            if next_ms <= previous_ms {
+                return Err(ClassifierError::ThresholdsNotIncreasing {
+                    previous: previous_ms,
+                    next: next_ms,
+                });
+            }
+            if next_class <= previous_class {
+                return Err(ClassifierError::ClassesNotSlower {
+                    previous: previous_class,
+                    next: next_class,
+                });
+            }
+        }
+        if let Some(&(_, last_class)) = tiers.last()
+            && slowest <= last_class
+        {
+            return Err(ClassifierError::ClassesNotSlower {
+                previous: last_class,
+                next: slowest,
+            });
+        }
+        Ok(Self { tiers, slowest })
+    }
+
+    pub fn classify(&self, ms: u64) -> SpeedClass {
+        self.tiers
+            .iter()
+            .find(|(max_ms, _)| ms <= *max_ms)
+            .map_or(self.slowest, |&(_, class)| class)
+    }
+
+    // 샘플 여러 개를 stat으로 줄인 뒤 분류합니다. 샘플이 없으면 None입니다.
+    pub fn classify_window(&self, samples: &[u64], stat: WindowStat) -> Option<SpeedClass> {
+        window_latency(samples, stat).map(|ms| self.classify(ms))
+    }
+}
+
+// 샘플 창의 대표 지연 시간(ms). 샘플이 없으면 None입니다.
+pub fn window_latency(samples: &[u64], stat: WindowStat) -> Option<u64> {
+    if samples.is_empty() {
+        return None;
+    }
+    match stat {
+        WindowStat::Percentile(p) => {
+            let mut sorted = samples.to_vec();
+            sorted.sort_unstable();
+            let rank = ((p.clamp(0.0, 100.0) / 100.0) * sorted.len() as f64).ceil() as usize;
+            Some(sorted[rank.saturating_sub(1)])
+        }
+        WindowStat::MeanPlusJitter => {
+            let count = samples.len() as u128;
+            let mean = samples.iter().map(|&ms| ms as u128).sum::<u128>() / count;
+            // 샘플이 하나면 비교할 이웃이 없으므로 지터는 0입니다.
+            let jitter = if samples.len() < 2 {
+                0
+            } else {
+                samples
+                    .windows(2)
+                    .map(|pair| pair[0].abs_diff(pair[1]) as u128)
+                    .sum::<u128>()
+                    / (count - 1)
+            };
+            Some(u64::try_from(mean + jitter).unwrap_or(u64::MAX))
+        }
+    }
+}
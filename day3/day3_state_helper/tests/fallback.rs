@@ -0,0 +1,76 @@
+use day3_state_helper::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    // 성공한 조회는 Real로, 실패는 마지막 정상 슬롯으로 대체되는지 확인합니다
+    fn test_last_known_good() {
+        let mut fallback = SlotFallback::new(FallbackStrategy::LastKnownGood);
+
+        // 아직 정상 슬롯이 없으면 대신할 값이 없습니다
+        assert_eq!(
+            fallback.resolve(Err("rpc timeout".to_string())),
+            Err(FallbackError::NoLastKnownGood {
+                reason: "rpc timeout".to_string()
+            })
+        );
+
+        assert_eq!(fallback.resolve(Ok(42)), Ok(SlotValue::Real(42)));
+        let value = fallback
+            .resolve(Err("connection reset".to_string()))
+            .unwrap();
+        assert_eq!(
+            value,
+            SlotValue::Substituted {
+                value: 42,
+                reason: "connection reset".to_string()
+            }
+        );
+        assert_eq!(value.value(), 42);
+        assert!(value.is_substituted());
+        assert_eq!(fallback.failures(), 2);
+        assert_eq!(fallback.last_reason(), Some("connection reset"));
+    }
+
+    #[test]
+    // 진짜 슬롯 0과 대체된 0을 구분할 수 있는지 확인합니다
+    fn test_default_is_distinguishable_from_real_zero() {
+        let mut fallback = SlotFallback::new(FallbackStrategy::Default(0));
+
+        let real = fallback.resolve(Ok(0)).unwrap();
+        let substituted = fallback.resolve(Err("not found".to_string())).unwrap();
+        assert_eq!(real.value(), substituted.value());
+        assert!(!real.is_substituted());
+        assert!(substituted.is_substituted());
+        // 기존 함수는 둘을 구분하지 못합니다
+        assert_eq!(
+            fallback_slot(Ok(0)),
+            fallback_slot(Err("not found".to_string()))
+        );
+    }
+
+    #[test]
+    // Propagate는 오류를 그대로 돌려주고, 훅이 모든 실패를 기록하는지 확인합니다
+    fn test_propagate_and_hook() {
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let recorded = Rc::clone(&events);
+        let mut fallback = SlotFallback::new(FallbackStrategy::Propagate).with_hook(move |event| {
+            recorded
+                .borrow_mut()
+                .push((event.reason.to_string(), event.substituted));
+        });
+
+        assert_eq!(fallback.resolve(Ok(7)), Ok(SlotValue::Real(7)));
+        let error = fallback
+            .resolve(Err("node offline".to_string()))
+            .unwrap_err();
+        assert_eq!(error.to_string(), "slot lookup failed: node offline");
+        assert_eq!(fallback.last_good(), Some(7));
+        assert_eq!(fallback.failures(), 1);
+        assert_eq!(*events.borrow(), vec![("node offline".to_string(), None)]);
+    }
+}
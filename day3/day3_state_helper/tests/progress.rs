@@ -0,0 +1,122 @@
+use day3_state_helper::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    // 테스트에서 직접 움직이는 시계
+    struct ManualClock(Cell<u64>);
+
+    impl ManualClock {
+        fn advance(&self, ms: u64) {
+            self.0.set(self.0.get() + ms);
+        }
+    }
+
+    impl Clock for ManualClock {
+        fn now_ms(&self) -> u64 {
+            self.0.get()
+        }
+    }
+
+    #[test]
+    // 샘플이 없거나 목표를 모를 때의 메시지를 확인합니다
+    fn test_progress_without_enough_data() {
+        let clock = ManualClock(Cell::new(0));
+        let mut tracker = SyncTracker::with_clock(&clock);
+        assert_eq!(tracker.progress().to_string(), "No blocks yet");
+
+        tracker.record_height(100);
+        let progress = tracker.progress();
+        assert_eq!(progress.current_height, Some(100));
+        assert_eq!(progress.percent, None);
+        assert_eq!(progress.to_string(), "Current height: 100 (target unknown)");
+
+        tracker.set_target(300);
+        assert_eq!(
+            tracker.progress().to_string(),
+            "Syncing: 100 / 300 (0.0%), measuring speed"
+        );
+    }
+
+    #[test]
+    // 진행률, 평활화된 속도, ETA를 계산하는지 확인합니다
+    fn test_progress_and_eta() {
+        let clock = ManualClock(Cell::new(1_000));
+        let mut tracker = SyncTracker::with_clock(&clock).with_rate_alpha(0.5);
+        tracker.set_target(1_000);
+        tracker.record_height(0);
+
+        clock.advance(1_000);
+        tracker.record_height(100); // 100 blocks/s
+        clock.advance(1_000);
+        tracker.record_height(150); // 50 blocks/s -> 평활화 75
+
+        let progress = tracker.progress();
+        assert_eq!(progress.percent, Some(15.0));
+        assert_eq!(progress.blocks_per_sec, Some(75.0));
+        // 남은 850블록 / 75 = 11.33초 -> 올림
+        assert_eq!(progress.eta_secs, Some(12));
+        assert!(!progress.is_synced());
+        assert_eq!(
+            progress.to_string(),
+            "Syncing: 150 / 1000 (15.0%), 75.0 blocks/s, ETA 12s"
+        );
+    }
+
+    #[test]
+    // 멈춘 노드는 ETA를 모르고, 따라잡으면 Synced로 보이는지 확인합니다
+    fn test_stalled_and_synced() {
+        let clock = ManualClock(Cell::new(0));
+        let mut tracker = SyncTracker::with_clock(&clock).with_rate_alpha(1.0);
+        tracker.set_target(10_000);
+        tracker.record_height(5_000);
+        clock.advance(2_000);
+        tracker.record_height(5_000);
+
+        let progress = tracker.progress();
+        assert_eq!(progress.blocks_per_sec, Some(0.0));
+        assert_eq!(progress.eta_secs, None);
+        assert!(progress.to_string().ends_with("0.0 blocks/s, ETA unknown"));
+
+        clock.advance(1_000);
+        tracker.record_height(10_002);
+        let progress = tracker.progress();
+        assert!(progress.is_synced());
+        assert_eq!(progress.percent, Some(100.0));
+        assert_eq!(progress.eta_secs, Some(0));
+        assert_eq!(progress.to_string(), "Synced at height 10002");
+    }
+
+    #[test]
+    // NaN이나 범위 밖의 가중치를 줘도 속도와 ETA가 NaN이 되지 않는지 확인합니다
+    fn test_rate_alpha_ignores_nan_and_clamps() {
+        for alpha in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY, -1.0, 2.0] {
+            let clock = ManualClock(Cell::new(0));
+            let mut tracker = SyncTracker::with_clock(&clock).with_rate_alpha(alpha);
+            tracker.set_target(1_000);
+            tracker.record_height(0);
+            clock.advance(1_000);
+            tracker.record_height(100);
+            clock.advance(1_000);
+            tracker.record_height(150);
+
+            let progress = tracker.progress();
+            let rate = progress.blocks_per_sec.unwrap();
+            assert!(
+                rate.is_finite() && (50.0..=100.0).contains(&rate),
+                "alpha {alpha}: {rate}"
+            );
+            assert!(progress.eta_secs.is_some());
+        }
+    }
+
+    #[test]
+    // 남은 시간을 읽기 쉬운 형태로 바꾸는지 확인합니다
+    fn test_format_duration() {
+        assert_eq!(format_duration(9), "9s");
+        assert_eq!(format_duration(245), "4m 05s");
+        assert_eq!(format_duration(3_723), "1h 02m 03s");
+    }
+}
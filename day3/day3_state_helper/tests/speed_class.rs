@@ -0,0 +1,118 @@
+use day3_state_helper::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    // 기본 분류기는 classify_peer_speed와 같은 경계를 쓰는지 확인합니다
+    fn test_default_classifier_matches_classify_peer_speed() {
+        let classifier = SpeedClassifier::default();
+        for ms in [0, 150, 151, 400, 401, 10_000] {
+            assert_eq!(
+                classifier.classify(ms).label(Locale::English),
+                classify_peer_speed(ms)
+            );
+        }
+        assert_eq!(classifier.classify(150), SpeedClass::Instant);
+        assert_eq!(classifier.classify(401), SpeedClass::Lagging);
+    }
+
+    #[test]
+    // 경계값과 등급 수를 바꿔 더 잘게 나눌 수 있는지 확인합니다
+    fn test_custom_tiers() {
+        let classifier = SpeedClassifier::new(
+            vec![
+                (50, SpeedClass::Instant),
+                (100, SpeedClass::Fast),
+                (250, SpeedClass::Acceptable),
+                (600, SpeedClass::Slow),
+            ],
+            SpeedClass::Lagging,
+        )
+        .unwrap();
+
+        assert_eq!(classifier.classify(50), SpeedClass::Instant);
+        assert_eq!(classifier.classify(51), SpeedClass::Fast);
+        assert_eq!(classifier.classify(250), SpeedClass::Acceptable);
+        assert_eq!(classifier.classify(600), SpeedClass::Slow);
+        assert_eq!(classifier.classify(601), SpeedClass::Lagging);
+    }
+
+    #[test]
+    // 겹치거나 뒤집힌 설정은 거절하는지 확인합니다
+    fn test_rejects_invalid_tiers() {
+        assert_eq!(
+            SpeedClassifier::new(
+                vec![(200, SpeedClass::Instant), (200, SpeedClass::Slow)],
+                SpeedClass::Lagging
+            ),
+            Err(ClassifierError::ThresholdsNotIncreasing {
+                previous: 200,
+                next: 200
+            })
+        );
+        assert_eq!(
+            SpeedClassifier::new(
+                vec![(100, SpeedClass::Slow), (200, SpeedClass::Fast)],
+                SpeedClass::Lagging
+            ),
+            Err(ClassifierError::ClassesNotSlower {
+                previous: SpeedClass::Slow,
+                next: SpeedClass::Fast
+            })
+        );
+        assert!(SpeedClassifier::new(vec![(100, SpeedClass::Lagging)], SpeedClass::Slow).is_err());
+        // 경계가 하나도 없으면 모든 값이 slowest 등급
+        let flat = SpeedClassifier::new(vec![], SpeedClass::Acceptable).unwrap();
+        assert_eq!(flat.classify(0), SpeedClass::Acceptable);
+    }
+
+    #[test]
+    // 한 번 튄 값보다 창 전체의 백분위수/지터로 분류하는지 확인합니다
+    fn test_classify_window() {
+        let classifier = SpeedClassifier::default();
+        // 대부분 빠르고 한 번만 튄 피어
+        let spiky = [100, 110, 90, 120, 900, 100, 105, 95, 110, 100];
+        assert_eq!(
+            window_latency(&spiky, WindowStat::Percentile(50.0)),
+            Some(100)
+        );
+        assert_eq!(
+            classifier.classify_window(&spiky, WindowStat::Percentile(90.0)),
+            Some(SpeedClass::Instant)
+        );
+        assert_eq!(
+            classifier.classify_window(&spiky, WindowStat::Percentile(100.0)),
+            Some(SpeedClass::Lagging)
+        );
+
+        // 평균은 빠르지만 들쭉날쭉한 피어: 평균 100 + 지터 100 = 200
+        let jittery = [50, 150, 50, 150, 50, 150];
+        assert_eq!(
+            window_latency(&jittery, WindowStat::MeanPlusJitter),
+            Some(200)
+        );
+        assert_eq!(
+            classifier.classify_window(&jittery, WindowStat::MeanPlusJitter),
+            Some(SpeedClass::Acceptable)
+        );
+
+        assert_eq!(
+            window_latency(&[120], WindowStat::MeanPlusJitter),
+            Some(120)
+        );
+        assert_eq!(
+            classifier.classify_window(&[], WindowStat::Percentile(50.0)),
+            None
+        );
+    }
+
+    #[test]
+    // 같은 등급을 한국어와 영어로 보여 주는지 확인합니다
+    fn test_localized_labels() {
+        assert_eq!(SpeedClass::Instant.label(Locale::Korean), "즉시");
+        assert_eq!(SpeedClass::Lagging.label(Locale::Korean), "지연");
+        assert_eq!(SpeedClass::Slow.label(Locale::English), "Slow");
+    }
+}
@@ -0,0 +1,191 @@
+// 피어/멤풀 스냅샷(JSON)을 읽어 노드 상태를 한 화면에 보여 주는 CLI입니다.
+// 사용법: node-dashboard --peers <FILE|-> [--mempool <FILE|->] [--watch <SECONDS>]
+//   --peers    NodePeer 배열 JSON 파일. "-"면 표준 입력에서 읽습니다.
+//   --mempool  PendingTx 배열 JSON 파일. 생략하면 빈 멤풀로 봅니다.
+//   --watch    주어진 초마다 파일을 다시 읽어 화면을 갱신합니다. 표준 입력과는 함께 쓸 수 없습니다.
+
+use std::io::Read;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::thread;
+use std::time::Duration;
+
+use day4_node_dashboard::{NodePeer, render_report};
+use day5_mempool_pipeline::PendingTx;
+
+const USAGE: &str =
+    "usage: node-dashboard --peers <FILE|-> [--mempool <FILE|->] [--watch <SECONDS>]";
+
+// 화면을 지우고 커서를 맨 위로 옮기는 ANSI 시퀀스
+const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+#[derive(Debug, thiserror::Error)]
+enum CliError {
+    #[error("{USAGE}")]
+    Help,
+    #[error("{0}\n{USAGE}")]
+    Usage(String),
+    #[error("failed to read {source_name}: {error}")]
+    Read {
+        source_name: String,
+        error: std::io::Error,
+    },
+    #[error("failed to parse {source_name}: {error}")]
+    Parse {
+        source_name: String,
+        error: serde_json::Error,
+    },
+}
+
+// 입력 위치. 표준 입력은 한 번만 읽을 수 있으므로 따로 구분합니다.
+#[derive(Debug, Clone)]
+enum Input {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Input {
+    fn parse(value: &str) -> Self {
+        if value == "-" {
+            Input::Stdin
+        } else {
+            Input::File(PathBuf::from(value))
+        }
+    }
+
+    fn name(&self) -> String {
+        match self {
+            Input::Stdin => "stdin".to_string(),
+            Input::File(path) => path.display().to_string(),
+        }
+    }
+
+    fn load<T: serde::de::DeserializeOwned>(&self) -> Result<T, CliError> {
+        let read_error = |error| CliError::Read {
+            source_name: self.name(),
+            error,
+        };
+        let text = match self {
+            Input::Stdin => {
+                let mut text = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut text)
+                    .map_err(read_error)?;
+                text
+            }
+            Input::File(path) => std::fs::read_to_string(path).map_err(read_error)?,
+        };
+        serde_json::from_str(&text).map_err(|error| CliError::Parse {
+            source_name: self.name(),
+            error,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct Args {
+    peers: Input,
+    mempool: Option<Input>,
+    watch: Option<Duration>,
+}
+
+impl Args {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, CliError> {
+        let mut peers = None;
+        let mut mempool = None;
+        let mut watch = None;
+        while let Some(flag) = args.next() {
+            let mut value = || {
+                args.next()
+                    .ok_or_else(|| CliError::Usage(format!("{flag} needs a value")))
+            };
+            match flag.as_str() {
+                "--peers" => peers = Some(Input::parse(&value()?)),
+                "--mempool" => mempool = Some(Input::parse(&value()?)),
+                "--watch" => {
+                    let raw = value()?;
+                    let seconds: u64 =
+                        raw.parse()
+                            .ok()
+                            .filter(|&seconds| seconds > 0)
+                            .ok_or_else(|| {
+                                CliError::Usage(format!(
+                                    "--watch expects a positive number of seconds, got {raw}"
+                                ))
+                            })?;
+                    watch = Some(Duration::from_secs(seconds));
+                }
+                "-h" | "--help" => return Err(CliError::Help),
+                other => return Err(CliError::Usage(format!("unknown argument {other}"))),
+            }
+        }
+
+        let peers = peers.ok_or_else(|| CliError::Usage("--peers is required".to_string()))?;
+        let stdin_inputs = [Some(&peers), mempool.as_ref()]
+            .into_iter()
+            .flatten()
+            .filter(|input| matches!(input, Input::Stdin))
+            .count();
+        if stdin_inputs > 1 {
+            return Err(CliError::Usage(
+                "only one input can be read from stdin".to_string(),
+            ));
+        }
+        if watch.is_some() && stdin_inputs > 0 {
+            return Err(CliError::Usage(
+                "--watch re-reads its inputs and cannot use stdin".to_string(),
+            ));
+        }
+        Ok(Self {
+            peers,
+            mempool,
+            watch,
+        })
+    }
+
+    // 입력을 모두 읽어 보고서 한 장을 만듭니다.
+    fn render(&self) -> Result<String, CliError> {
+        let peers: Vec<NodePeer> = self.peers.load()?;
+        let mempool: Vec<PendingTx> = match &self.mempool {
+            Some(input) => input.load()?,
+            None => Vec::new(),
+        };
+        Ok(render_report(&peers, &mempool))
+    }
+}
+
+fn main() -> ExitCode {
+    let args = match Args::parse(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(CliError::Help) => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let Some(interval) = args.watch else {
+        return match args.render() {
+            Ok(report) => {
+                print!("{report}");
+                ExitCode::SUCCESS
+            }
+            Err(error) => {
+                eprintln!("{error}");
+                ExitCode::FAILURE
+            }
+        };
+    };
+
+    // watch 모드에서는 파일이 쓰이는 도중이라 읽기에 실패해도 멈추지 않고, 오류를 화면에 보여 준 뒤 다음 주기에 다시 읽습니다.
+    loop {
+        let frame = args
+            .render()
+            .unwrap_or_else(|error| format!("error: {error}\n"));
+        print!("{CLEAR_SCREEN}{frame}");
+        thread::sleep(interval);
+    }
+}
@@ -0,0 +1,152 @@
+use crate::NodePeer;
+
+// 네트워크 건강 판정에 쓰는 문턱값입니다.
+// Thresholds that turn peer slot lags into a health verdict.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HealthThresholds {
+    // 중앙값 피어가 최고 슬롯보다 이만큼 이하로 뒤처지면 Synced
+    pub synced_max_lag: u64,
+    // 이보다 더 뒤처지면 따라잡는 중이 아니라 멈춘 것(Stalled)으로 봅니다.
+    pub catching_up_max_lag: u64,
+    // 슬롯을 아는 피어가 이보다 적으면 비교할 대상이 없으므로 Isolated
+    pub min_initialized_peers: usize,
+    // 지연 구간의 상한들(포함). [0, 4]면 0 / 1..=4 / 5 이상 세 구간이 됩니다. 순서와 중복은 계산할 때 정리합니다.
+    pub lag_buckets: Vec<u64>,
+}
+
+impl Default for HealthThresholds {
+    fn default() -> Self {
+        Self {
+            synced_max_lag: 2,
+            catching_up_max_lag: 64,
+            min_initialized_peers: 1,
+            lag_buckets: vec![0, 2, 16, 64],
+        }
+    }
+}
+
+// 네트워크 전체 판정입니다. 문자열 대신 이 값으로 알림 여부를 결정하세요.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HealthVerdict {
+    Synced,
+    CatchingUp,
+    Stalled,
+    Isolated,
+}
+
+impl HealthVerdict {
+    // Synced가 아니면 알림 대상입니다.
+    pub fn needs_alert(&self) -> bool {
+        !matches!(self, HealthVerdict::Synced)
+    }
+}
+
+// 피어 하나가 최고 슬롯보다 얼마나 뒤처졌는지. 슬롯을 모르는 피어는 lag가 None입니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PeerLag {
+    pub name: String,
+    pub lag: Option<u64>,
+}
+
+// min..=max 슬롯만큼 뒤처진 피어 수. 마지막 구간은 max가 None(상한 없음)입니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LagBucket {
+    pub min_lag: u64,
+    pub max_lag: Option<u64>,
+    pub peers: usize,
+}
+
+// summarize_slots의 문자열 대신, 피어 슬롯으로 계산한 네트워크 전체 요약입니다.
+// An aggregate view of the network computed from every peer's last_slot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NetworkHealth {
+    pub highest_slot: Option<u64>,
+    // 슬롯을 아는 피어들의 중앙값. 개수가 짝수면 실제 보고된 슬롯이 되도록 아래쪽 값을 씁니다.
+    pub median_slot: Option<u64>,
+    // 입력 순서 그대로의 피어별 지연
+    pub lags: Vec<PeerLag>,
+    pub buckets: Vec<LagBucket>,
+    pub uninitialized: usize,
+    pub verdict: HealthVerdict,
+}
+
+// 피어 목록에서 네트워크 건강 요약을 계산합니다.
+// 판정은 "중앙값 피어가 최고 슬롯에서 얼마나 떨어져 있는가"로 내립니다.
+// 한두 피어만 앞서 나가거나 뒤처져도 다수의 상태가 판정을 정하도록 중앙값을 씁니다.
+pub fn network_health(peers: &[NodePeer], thresholds: &HealthThresholds) -> NetworkHealth {
+    let mut slots: Vec<u64> = peers.iter().filter_map(|peer| peer.last_slot).collect();
+    slots.sort_unstable();
+    let highest_slot = slots.last().copied();
+    let median_slot = if slots.is_empty() {
+        None
+    } else {
+        Some(slots[(slots.len() - 1) / 2])
+    };
+
+    let lags: Vec<PeerLag> = peers
+        .iter()
+        .map(|peer| PeerLag {
+            name: peer.name.clone(),
+            lag: peer
+                .last_slot
+                .zip(highest_slot)
+                .map(|(slot, highest)| highest - slot),
+        })
+        .collect();
+
+    // 상한이 뒤섞여 있거나 겹쳐도 구간이 뒤집히지 않도록 정렬하고 중복을 없앱니다.
+    let mut bounds = thresholds.lag_buckets.clone();
+    bounds.sort_unstable();
+    bounds.dedup();
+    let mut buckets = Vec::new();
+    let mut min_lag = Some(0);
+    for max_lag in bounds {
+        let Some(start) = min_lag else { break };
+        buckets.push(LagBucket {
+            min_lag: start,
+            max_lag: Some(max_lag),
+            peers: 0,
+        });
+        min_lag = max_lag.checked_add(1);
+    }
+    // 상한이 u64::MAX였다면 그 뒤로는 구간이 없으므로 열린 구간을 만들지 않습니다.
+    if let Some(min_lag) = min_lag {
+        buckets.push(LagBucket {
+            min_lag,
+            max_lag: None,
+            peers: 0,
+        });
+    }
+    for lag in lags.iter().filter_map(|peer| peer.lag) {
+        // 구간은 오름차순이므로 처음으로 상한 안에 들어오는 구간이 정답입니다.
+        if let Some(bucket) = buckets
+            .iter_mut()
+            .find(|bucket| bucket.max_lag.is_none_or(|max| lag <= max))
+        {
+            bucket.peers += 1;
+        }
+    }
+
+    let verdict = match (highest_slot, median_slot) {
+        (Some(highest), Some(median)) if slots.len() >= thresholds.min_initialized_peers => {
+            let median_lag = highest - median;
+            if median_lag <= thresholds.synced_max_lag {
+                HealthVerdict::Synced
+            } else if median_lag <= thresholds.catching_up_max_lag {
+                HealthVerdict::CatchingUp
+            } else {
+                HealthVerdict::Stalled
+            }
+        }
+        _ => HealthVerdict::Isolated,
+    };
+
+    NetworkHealth {
+        highest_slot,
+        median_slot,
+        lags,
+        buckets,
+        uninitialized: peers.len() - slots.len(),
+        verdict,
+    }
+}
@@ -1,3 +1,23 @@
+mod health;
+mod registry;
+mod render;
+mod report;
+mod reputation;
+mod selector;
+mod snapshot;
+
+pub use health::{
+    HealthThresholds, HealthVerdict, LagBucket, NetworkHealth, PeerLag, network_health,
+};
+pub use registry::{PeerRegistry, PeerUpdate, RegistryError};
+pub use render::{CsvRenderer, DashboardRenderer, JsonRenderer, PrometheusRenderer, TableRenderer};
+pub use report::render_report;
+pub use reputation::{PeerReputation, ReputationBook, ReputationConfig, best_peers};
+pub use selector::{
+    HighestSlot, LowestLatency, PeerSelector, RoundRobin, WeightedRandom, eligible_peers,
+};
+pub use snapshot::{PeerChange, PeerSnapshot, SNAPSHOT_VERSION, SnapshotDiff, SnapshotError};
+
 // - 아래 함수를 순서대로 구현합니다. 각 함수 위에도 주석을 남겨 "이 함수가 어떤 문제를 해결하는지" 설명합니다.
 
 // - `NodePeer` 구조체를 만들고 아래 필드를 정의합니다.
@@ -7,7 +27,7 @@
 
 // NodePeer 구조체는 우리가 피어(노드)들이 어떻게 작동하는지 이해하고, 가장 빠른 피어(노드)를 찾아내는 데 도와줍니다.
 // It helps us understand how the peers are functioning and find the fastest one.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct NodePeer {
     pub name: String,
     pub last_slot: Option<u64>,
@@ -33,6 +53,8 @@ pub fn count_uninitialized(peers: &[NodePeer]) -> usize {
 //    - 피어가 하나도 없다면 `None`을 반환합니다.
 //    - 최소값을 갱신할 때는 `match`나 `if let`으로 현재까지 찾은 피어를 비교해 주세요.
 // 이 함수는 여러 피어 중에서 가장 빠른 응답 속도를 가진 피어를 찾아서 최적의 통신 파트너를 선택합니다.
+// 한 번 잰 지연 시간만 보므로, 누적 기록을 반영하려면 reputation::best_peers를 쓰세요.
+// 요청을 여러 피어로 나누려면 PeerSelector 전략을 쓰세요.
 pub fn fastest_peer<'a>(peers: &'a [NodePeer]) -> Option<&'a NodePeer> {
     let mut fastest_peer = None;
     let mut min_latency = u64::MAX;
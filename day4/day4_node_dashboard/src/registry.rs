@@ -0,0 +1,144 @@
+use std::collections::HashMap;
+
+use crate::{
+    HealthThresholds, NetworkHealth, NodePeer, count_uninitialized, fastest_peer, network_health,
+    summarize_slots,
+};
+
+// 피어에게서 들어오는 이벤트입니다. 레지스트리는 이 이벤트로만 피어 상태를 바꿉니다.
+// Events reported by (or about) a peer; the registry applies them in place.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PeerUpdate {
+    // 피어가 새 슬롯까지 받았다고 알려 옴
+    SlotReported(u64),
+    // 새로 잰 왕복 지연 시간(ms)
+    LatencySample(u64),
+    // 연결이 끊김. 피어가 레지스트리에서 빠집니다.
+    Disconnected,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum RegistryError {
+    #[error("peer {name} is already registered")]
+    AlreadyRegistered { name: String },
+    #[error("peer {name} is not registered")]
+    UnknownPeer { name: String },
+}
+
+// 피어 목록을 소유하고 갱신하는 레지스트리입니다.
+// 피어는 등록 순서대로 Vec에 두고, 이름 → 위치 인덱스로 바로 찾습니다.
+// peers()가 슬라이스를 돌려주므로 count_uninitialized 같은 기존 함수를 그대로 쓸 수 있습니다.
+#[derive(Debug, Default)]
+pub struct PeerRegistry {
+    peers: Vec<NodePeer>,
+    // peers와 같은 위치에, 각 피어에게서 마지막으로 소식을 들은 시각(ms)
+    last_seen_ms: Vec<u64>,
+    index_by_name: HashMap<String, usize>,
+}
+
+impl PeerRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // 새 피어를 등록합니다. 같은 이름이 이미 있으면 덮어쓰지 않고 오류를 돌려줍니다.
+    pub fn add(&mut self, peer: NodePeer, now_ms: u64) -> Result<(), RegistryError> {
+        if self.index_by_name.contains_key(&peer.name) {
+            return Err(RegistryError::AlreadyRegistered { name: peer.name });
+        }
+        self.index_by_name
+            .insert(peer.name.clone(), self.peers.len());
+        self.peers.push(peer);
+        self.last_seen_ms.push(now_ms);
+        Ok(())
+    }
+
+    // 이벤트를 적용하고 마지막 소식 시각을 now_ms로 갱신합니다.
+    // Disconnected면 피어를 빼고, 빠진 피어를 돌려줍니다.
+    pub fn apply(
+        &mut self,
+        name: &str,
+        update: PeerUpdate,
+        now_ms: u64,
+    ) -> Result<Option<NodePeer>, RegistryError> {
+        let Some(&index) = self.index_by_name.get(name) else {
+            return Err(RegistryError::UnknownPeer {
+                name: name.to_string(),
+            });
+        };
+        match update {
+            // 슬롯은 보고된 값 그대로 씁니다. 포크를 따라간 피어는 슬롯이 내려갈 수도 있기 때문입니다.
+            PeerUpdate::SlotReported(slot) => self.peers[index].last_slot = Some(slot),
+            PeerUpdate::LatencySample(latency_ms) => self.peers[index].latency_ms = latency_ms,
+            PeerUpdate::Disconnected => return Ok(self.remove(name)),
+        }
+        self.last_seen_ms[index] = now_ms;
+        Ok(None)
+    }
+
+    // 피어를 뺍니다. 남은 피어들의 등록 순서는 유지됩니다.
+    pub fn remove(&mut self, name: &str) -> Option<NodePeer> {
+        let index = self.index_by_name.remove(name)?;
+        let peer = self.peers.remove(index);
+        self.last_seen_ms.remove(index);
+        // 뒤에 있던 피어들이 한 칸씩 당겨졌으므로 인덱스를 다시 맞춥니다.
+        for (shifted, peer) in self.peers.iter().enumerate().skip(index) {
+            self.index_by_name.insert(peer.name.clone(), shifted);
+        }
+        Some(peer)
+    }
+
+    // now_ms 기준으로 timeout_ms보다 오래 소식이 없던 피어들을 빼고 돌려줍니다.
+    pub fn expire_silent(&mut self, now_ms: u64, timeout_ms: u64) -> Vec<NodePeer> {
+        let silent: Vec<String> = self
+            .peers
+            .iter()
+            .zip(&self.last_seen_ms)
+            .filter(|&(_, &seen)| now_ms.saturating_sub(seen) > timeout_ms)
+            .map(|(peer, _)| peer.name.clone())
+            .collect();
+        silent.iter().filter_map(|name| self.remove(name)).collect()
+    }
+
+    pub fn get(&self, name: &str) -> Option<&NodePeer> {
+        self.index_by_name
+            .get(name)
+            .map(|&index| &self.peers[index])
+    }
+
+    pub fn last_seen(&self, name: &str) -> Option<u64> {
+        self.index_by_name
+            .get(name)
+            .map(|&index| self.last_seen_ms[index])
+    }
+
+    // 등록 순서대로 피어를 빌려줍니다.
+    pub fn peers(&self) -> &[NodePeer] {
+        &self.peers
+    }
+
+    pub fn len(&self) -> usize {
+        self.peers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.peers.is_empty()
+    }
+
+    // 아래 메서드들은 슬라이스 함수를 레지스트리 위에서 그대로 보여 주는 뷰입니다.
+    pub fn count_uninitialized(&self) -> usize {
+        count_uninitialized(&self.peers)
+    }
+
+    pub fn fastest_peer(&self) -> Option<&NodePeer> {
+        fastest_peer(&self.peers)
+    }
+
+    pub fn summarize_slots(&self) -> Vec<String> {
+        summarize_slots(&self.peers)
+    }
+
+    pub fn network_health(&self, thresholds: &HealthThresholds) -> NetworkHealth {
+        network_health(&self.peers, thresholds)
+    }
+}
@@ -0,0 +1,158 @@
+use std::fmt::Write;
+
+use crate::{NodePeer, count_uninitialized};
+
+// summarize_slots가 사람을 위한 문장을 만든다면, 렌더러는 같은 피어 데이터를 기계나 터미널이 읽기 좋은 형식으로 바꿉니다.
+// 새 형식이 필요하면 이 트레이트를 구현하기만 하면 됩니다.
+// Turns the same peer list into a specific output format.
+pub trait DashboardRenderer {
+    fn render(&self, peers: &[NodePeer]) -> String;
+}
+
+// serde_json으로 피어 배열을 그대로 직렬화합니다. 슬롯을 모르면 null입니다.
+pub struct JsonRenderer;
+
+impl DashboardRenderer for JsonRenderer {
+    fn render(&self, peers: &[NodePeer]) -> String {
+        serde_json::to_string_pretty(peers).expect("NodePeer always serializes")
+    }
+}
+
+// RFC 4180 형식의 CSV. 헤더 한 줄 뒤에 피어마다 한 줄이며, 슬롯을 모르면 빈 칸입니다.
+pub struct CsvRenderer;
+
+impl DashboardRenderer for CsvRenderer {
+    fn render(&self, peers: &[NodePeer]) -> String {
+        let mut out = String::from("name,last_slot,latency_ms\n");
+        for peer in peers {
+            let slot = peer
+                .last_slot
+                .map(|slot| slot.to_string())
+                .unwrap_or_default();
+            writeln!(
+                out,
+                "{},{},{}",
+                csv_field(&peer.name),
+                slot,
+                peer.latency_ms
+            )
+            .unwrap();
+        }
+        out
+    }
+}
+
+// 쉼표, 따옴표, 줄바꿈이 들어간 값만 따옴표로 감싸고, 안의 따옴표는 두 번 씁니다.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+// Prometheus 텍스트 노출 형식(exposition format)입니다. 스크레이퍼가 그대로 수집할 수 있습니다.
+// 슬롯을 모르는 피어는 last_slot 지표에서 빠지고, 대신 미초기화 피어 수로 집계됩니다.
+pub struct PrometheusRenderer;
+
+impl DashboardRenderer for PrometheusRenderer {
+    fn render(&self, peers: &[NodePeer]) -> String {
+        let mut out = String::new();
+        out.push_str("# HELP node_peer_last_slot Last slot reported by the peer.\n");
+        out.push_str("# TYPE node_peer_last_slot gauge\n");
+        for peer in peers {
+            if let Some(slot) = peer.last_slot {
+                writeln!(
+                    out,
+                    "node_peer_last_slot{{peer=\"{}\"}} {}",
+                    prometheus_label(&peer.name),
+                    slot
+                )
+                .unwrap();
+            }
+        }
+        out.push_str(
+            "# HELP node_peer_latency_ms Round-trip latency to the peer in milliseconds.\n",
+        );
+        out.push_str("# TYPE node_peer_latency_ms gauge\n");
+        for peer in peers {
+            writeln!(
+                out,
+                "node_peer_latency_ms{{peer=\"{}\"}} {}",
+                prometheus_label(&peer.name),
+                peer.latency_ms
+            )
+            .unwrap();
+        }
+        out.push_str("# HELP node_peers_uninitialized Peers that have not reported a slot yet.\n");
+        out.push_str("# TYPE node_peers_uninitialized gauge\n");
+        writeln!(
+            out,
+            "node_peers_uninitialized {}",
+            count_uninitialized(peers)
+        )
+        .unwrap();
+        out
+    }
+}
+
+// 라벨 값 안의 역슬래시, 따옴표, 줄바꿈은 이스케이프해야 합니다.
+fn prometheus_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+// 터미널용 고정폭 표. 열 너비는 가장 긴 값에 맞추고, 숫자는 오른쪽 정렬합니다.
+pub struct TableRenderer;
+
+impl DashboardRenderer for TableRenderer {
+    fn render(&self, peers: &[NodePeer]) -> String {
+        let rows: Vec<Vec<String>> = peers
+            .iter()
+            .map(|peer| {
+                vec![
+                    peer.name.clone(),
+                    peer.last_slot
+                        .map_or_else(|| "-".to_string(), |slot| slot.to_string()),
+                    peer.latency_ms.to_string(),
+                ]
+            })
+            .collect();
+        fixed_width_table(&["PEER", "LAST SLOT", "LATENCY MS"], &rows)
+    }
+}
+
+// 첫 열은 왼쪽, 나머지 열(숫자)은 오른쪽으로 정렬한 고정폭 표를 만듭니다. 열 사이는 공백 두 칸입니다.
+pub(crate) fn fixed_width_table(header: &[&str], rows: &[Vec<String>]) -> String {
+    let mut widths: Vec<usize> = header.iter().map(|title| title.chars().count()).collect();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut out = String::new();
+    let mut push_row = |cells: &mut dyn Iterator<Item = &str>| {
+        let line: Vec<String> = cells
+            .zip(&widths)
+            .enumerate()
+            .map(|(column, (cell, &width))| {
+                if column == 0 {
+                    format!("{cell:<width$}")
+                } else {
+                    format!("{cell:>width$}")
+                }
+            })
+            .collect();
+        writeln!(out, "{}", line.join("  ")).unwrap();
+    };
+    push_row(&mut header.iter().copied());
+    let rules: Vec<String> = widths.iter().map(|&width| "-".repeat(width)).collect();
+    push_row(&mut rules.iter().map(String::as_str));
+    for row in rows {
+        push_row(&mut row.iter().map(String::as_str));
+    }
+    out
+}
@@ -0,0 +1,133 @@
+use std::fmt::Write;
+
+use day3_state_helper::classify_peer_speed;
+use day5_mempool_pipeline::{PendingTx, TxStatus, compute_account_stats, group_by_account};
+use day6_fee_scheduler::{MempoolEntry, PriorityScheduler, TxClass};
+
+use crate::render::fixed_width_table;
+use crate::{HealthThresholds, NodePeer, network_health, summarize_slots};
+
+// 스케줄러 섹션에서 보여 줄 다음 실행 후보 수
+const NEXT_UP: usize = 5;
+
+// 시뮬레이션 전 트랜잭션은 compute unit을 모르므로, 스케줄러 한도(200_000)를 쓴다고 가정합니다.
+const UNKNOWN_COMPUTE_UNITS: u32 = 200_000;
+
+// 당직자가 한 화면에서 노드 상태를 볼 수 있도록 피어, 멤풀, 스케줄러 요약을 하나의 텍스트로 묶습니다.
+// node-dashboard 바이너리가 이 함수를 그대로 출력합니다.
+// One screen of node health: peers, mempool accounts and the fee scheduler queue.
+pub fn render_report(peers: &[NodePeer], mempool: &[PendingTx]) -> String {
+    let mut out = String::new();
+    render_peers(&mut out, peers);
+    out.push('\n');
+    render_mempool(&mut out, mempool);
+    out.push('\n');
+    render_scheduler(&mut out, mempool);
+    out
+}
+
+fn render_peers(out: &mut String, peers: &[NodePeer]) {
+    writeln!(out, "== Peers ({}) ==", peers.len()).unwrap();
+    for (summary, peer) in summarize_slots(peers).into_iter().zip(peers) {
+        writeln!(
+            out,
+            "{summary} [{}, {} ms]",
+            classify_peer_speed(peer.latency_ms),
+            peer.latency_ms
+        )
+        .unwrap();
+    }
+    let health = network_health(peers, &HealthThresholds::default());
+    let slot = |slot: Option<u64>| slot.map_or_else(|| "-".to_string(), |slot| slot.to_string());
+    writeln!(
+        out,
+        "network: {:?} (highest slot {}, median slot {}, {} awaiting first block)",
+        health.verdict,
+        slot(health.highest_slot),
+        slot(health.median_slot),
+        health.uninitialized
+    )
+    .unwrap();
+}
+
+fn render_mempool(out: &mut String, mempool: &[PendingTx]) {
+    let stats = compute_account_stats(&group_by_account(mempool));
+    writeln!(
+        out,
+        "== Mempool ({} txs, {} accounts) ==",
+        mempool.len(),
+        stats.len()
+    )
+    .unwrap();
+    let rows: Vec<Vec<String>> = stats
+        .iter()
+        .map(|(account, stats)| {
+            vec![
+                account.to_string(),
+                stats.tx_count().to_string(),
+                stats.pending.to_string(),
+                stats.simulated.to_string(),
+                stats.rejected.to_string(),
+                stats.total_fee.to_string(),
+                stats.total_bytes.to_string(),
+            ]
+        })
+        .collect();
+    out.push_str(&fixed_width_table(
+        &[
+            "ACCOUNT",
+            "TXS",
+            "PENDING",
+            "SIMULATED",
+            "REJECTED",
+            "TOTAL FEE",
+            "BYTES",
+        ],
+        &rows,
+    ));
+}
+
+// 거절되지 않은 트랜잭션으로 스케줄러를 새로 채워, 큐 크기와 다음에 실행될 트랜잭션들을 보여 줍니다.
+fn render_scheduler(out: &mut String, mempool: &[PendingTx]) {
+    let mut scheduler = PriorityScheduler::new();
+    let mut skipped = 0;
+    for tx in mempool {
+        let compute_units = match tx.status {
+            TxStatus::Pending => UNKNOWN_COMPUTE_UNITS,
+            TxStatus::Simulated { compute_units } => {
+                u32::try_from(compute_units).unwrap_or(u32::MAX)
+            }
+            TxStatus::Rejected { .. } => continue,
+        };
+        let entry = MempoolEntry {
+            id: tx.id,
+            fee_micro_lamports: tx.fee_micro_lamports,
+            compute_units,
+            payload_size: tx.payload_size,
+            class: TxClass::Standard,
+        };
+        // 수수료 0이나 compute unit 초과처럼 스케줄러가 받지 않는 트랜잭션은 따로 셉니다.
+        if scheduler.push(entry).is_err() {
+            skipped += 1;
+        }
+    }
+
+    writeln!(
+        out,
+        "== Scheduler ({} queued, {} skipped) ==",
+        scheduler.len(),
+        skipped
+    )
+    .unwrap();
+    let rows: Vec<Vec<String>> = std::iter::from_fn(|| scheduler.pop())
+        .take(NEXT_UP)
+        .map(|entry| {
+            vec![
+                entry.id.to_string(),
+                entry.fee_micro_lamports.to_string(),
+                entry.compute_units.to_string(),
+            ]
+        })
+        .collect();
+    out.push_str(&fixed_width_table(&["NEXT TX", "FEE", "CU"], &rows));
+}
@@ -0,0 +1,295 @@
+use std::collections::{BTreeMap, HashMap, VecDeque};
+
+use crate::NodePeer;
+
+// 평판 점수를 어떻게 계산할지 정하는 설정값입니다.
+// 점수는 지연 시간, 성공률, 슬롯 지연 세 가지를 0~1로 정규화한 뒤 가중 평균해 0~100으로 만듭니다.
+// Weights and thresholds for turning a peer's history into a single 0..=100 score.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReputationConfig {
+    // EWMA 평활 계수(0~1). 클수록 최근 샘플을 더 크게 반영합니다.
+    pub ewma_alpha: f64,
+    // 백분위수를 계산하기 위해 보관하는 최근 지연 샘플 수
+    pub history_len: usize,
+    pub latency_weight: f64,
+    pub reliability_weight: f64,
+    pub lag_weight: f64,
+    // 지연 점수가 0.5가 되는 지연 시간(ms). 이보다 빠르면 0.5보다 높습니다.
+    pub reference_latency_ms: f64,
+    // 슬롯 지연 점수가 0.5가 되는 슬롯 수
+    pub reference_lag_slots: f64,
+    // 점수가 이 값보다 낮아지면 밴합니다.
+    pub ban_below: f64,
+    // 밴된 피어는 점수가 이 값 이상으로 회복해야 풀립니다. ban_below보다 높게 두어 밴/해제가 반복되지 않게 합니다.
+    pub unban_at: f64,
+    // 성공/실패 횟수가 절반으로 줄어드는 시간(ms). 오래전 실패가 영원히 따라다니지 않게 합니다.
+    pub half_life_ms: u64,
+}
+
+impl Default for ReputationConfig {
+    fn default() -> Self {
+        Self {
+            ewma_alpha: 0.3,
+            history_len: 64,
+            latency_weight: 0.4,
+            reliability_weight: 0.4,
+            lag_weight: 0.2,
+            reference_latency_ms: 100.0,
+            reference_lag_slots: 8.0,
+            ban_below: 20.0,
+            unban_at: 40.0,
+            half_life_ms: 60_000,
+        }
+    }
+}
+
+// 피어 하나의 누적 기록입니다.
+// A single peer's rolling history.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PeerReputation {
+    // 지수 가중 이동 평균 지연 시간. 샘플이 없으면 None입니다.
+    pub ewma_latency_ms: Option<f64>,
+    // 감쇠가 적용되므로 정수가 아닌 f64 횟수입니다.
+    pub successes: f64,
+    pub failures: f64,
+    pub last_slot: Option<u64>,
+    pub banned: bool,
+    // 최근 지연 샘플(오래된 것부터)
+    latencies: VecDeque<u64>,
+    // 마지막으로 감쇠를 적용한 시각(ms)
+    decayed_at_ms: u64,
+}
+
+impl PeerReputation {
+    fn new(now_ms: u64) -> Self {
+        Self {
+            ewma_latency_ms: None,
+            successes: 0.0,
+            failures: 0.0,
+            last_slot: None,
+            banned: false,
+            latencies: VecDeque::new(),
+            decayed_at_ms: now_ms,
+        }
+    }
+
+    // 최근 지연 샘플의 p 백분위수(nearest-rank, p는 0~100). 샘플이 없으면 None입니다.
+    pub fn latency_percentile(&self, p: f64) -> Option<u64> {
+        if self.latencies.is_empty() {
+            return None;
+        }
+        let mut sorted: Vec<u64> = self.latencies.iter().copied().collect();
+        sorted.sort_unstable();
+        let rank = ((p.clamp(0.0, 100.0) / 100.0) * sorted.len() as f64).ceil() as usize;
+        Some(sorted[rank.saturating_sub(1)])
+    }
+
+    pub fn latency_samples(&self) -> usize {
+        self.latencies.len()
+    }
+
+    // 라플라스 보정을 한 성공률. 기록이 없으면 0.5(중립)입니다.
+    pub fn success_rate(&self) -> f64 {
+        (self.successes + 1.0) / (self.successes + self.failures + 2.0)
+    }
+}
+
+// 피어 이름 → 평판 기록. 샘플을 받을 때마다 점수와 밴 여부를 다시 판단합니다.
+// Tracks every peer's reputation and keeps ban state in sync with the score.
+#[derive(Debug, Clone, Default)]
+pub struct ReputationBook {
+    config: ReputationConfig,
+    peers: BTreeMap<String, PeerReputation>,
+}
+
+impl ReputationBook {
+    pub fn new(config: ReputationConfig) -> Self {
+        Self {
+            config,
+            peers: BTreeMap::new(),
+        }
+    }
+
+    pub fn config(&self) -> &ReputationConfig {
+        &self.config
+    }
+
+    pub fn get(&self, name: &str) -> Option<&PeerReputation> {
+        self.peers.get(name)
+    }
+
+    // 지연 샘플 하나를 EWMA와 백분위수 기록에 반영합니다.
+    pub fn record_latency(&mut self, name: &str, latency_ms: u64, now_ms: u64) {
+        let alpha = self.config.ewma_alpha;
+        let history_len = self.config.history_len;
+        self.update(name, now_ms, |peer| {
+            peer.ewma_latency_ms = Some(match peer.ewma_latency_ms {
+                Some(ewma) => alpha * latency_ms as f64 + (1.0 - alpha) * ewma,
+                None => latency_ms as f64,
+            });
+            peer.latencies.push_back(latency_ms);
+            while peer.latencies.len() > history_len {
+                peer.latencies.pop_front();
+            }
+        });
+    }
+
+    // 요청이 성공했을 때 호출합니다.
+    pub fn record_success(&mut self, name: &str, now_ms: u64) {
+        self.update(name, now_ms, |peer| peer.successes += 1.0);
+    }
+
+    // 요청이 실패(타임아웃, 잘못된 응답 등)했을 때 호출합니다.
+    pub fn record_failure(&mut self, name: &str, now_ms: u64) {
+        self.update(name, now_ms, |peer| peer.failures += 1.0);
+    }
+
+    pub fn record_slot(&mut self, name: &str, slot: u64, now_ms: u64) {
+        self.update(name, now_ms, |peer| {
+            peer.last_slot = Some(peer.last_slot.map_or(slot, |last| last.max(slot)));
+        });
+        // 가장 앞선 슬롯이 바뀌면 다른 피어들의 지연도 바뀌므로 밴 여부를 다시 봅니다.
+        self.refresh_bans();
+    }
+
+    // NodePeer 스냅샷(지연 한 번 + 마지막 슬롯)을 평판 기록에 반영합니다.
+    pub fn observe(&mut self, peer: &NodePeer, now_ms: u64) {
+        self.record_latency(&peer.name, peer.latency_ms, now_ms);
+        if let Some(slot) = peer.last_slot {
+            self.record_slot(&peer.name, slot, now_ms);
+        }
+    }
+
+    // 모든 피어의 성공/실패 횟수에 반감기 감쇠를 적용합니다.
+    // 시간이 지나면 성공률이 중립(0.5)으로 돌아가, 실패로 밴된 피어도 다시 기회를 얻습니다.
+    pub fn decay(&mut self, now_ms: u64) {
+        let half_life = self.config.half_life_ms.max(1) as f64;
+        for peer in self.peers.values_mut() {
+            let elapsed = now_ms.saturating_sub(peer.decayed_at_ms);
+            if elapsed == 0 {
+                continue;
+            }
+            let factor = 0.5f64.powf(elapsed as f64 / half_life);
+            peer.successes *= factor;
+            peer.failures *= factor;
+            peer.decayed_at_ms = now_ms;
+        }
+        self.refresh_bans();
+    }
+
+    // 지금까지 보고된 가장 높은 슬롯
+    pub fn best_slot(&self) -> Option<u64> {
+        self.peers.values().filter_map(|peer| peer.last_slot).max()
+    }
+
+    // 가장 앞선 피어보다 몇 슬롯 뒤처졌는지. 슬롯을 모르는 피어는 None입니다.
+    pub fn slot_lag(&self, name: &str) -> Option<u64> {
+        let slot = self.peers.get(name)?.last_slot?;
+        self.best_slot().map(|best| best - slot)
+    }
+
+    // 0~100 점수. 모르는 피어는 None입니다.
+    // 지연 샘플이 없으면 지연 점수는 중립(0.5), 슬롯을 모르면 슬롯 점수는 0입니다.
+    pub fn score(&self, name: &str) -> Option<f64> {
+        let peer = self.peers.get(name)?;
+        Some(self.score_of(peer, self.best_slot()))
+    }
+
+    pub fn is_banned(&self, name: &str) -> bool {
+        self.peers.get(name).is_some_and(|peer| peer.banned)
+    }
+
+    // 밴되지 않은 피어를 점수가 높은 순으로 최대 n개 돌려줍니다. 점수가 같으면 이름 순입니다.
+    pub fn best_peers(&self, n: usize) -> Vec<(&str, f64)> {
+        let best_slot = self.best_slot();
+        let mut ranked: Vec<(&str, f64)> = self
+            .peers
+            .iter()
+            .filter(|(_, peer)| !peer.banned)
+            .map(|(name, peer)| (name.as_str(), self.score_of(peer, best_slot)))
+            .collect();
+        ranked.sort_by(|(a_name, a), (b_name, b)| b.total_cmp(a).then_with(|| a_name.cmp(b_name)));
+        ranked.truncate(n);
+        ranked
+    }
+
+    pub fn len(&self) -> usize {
+        self.peers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.peers.is_empty()
+    }
+
+    fn score_of(&self, peer: &PeerReputation, best_slot: Option<u64>) -> f64 {
+        let config = &self.config;
+        let latency = peer.ewma_latency_ms.map_or(0.5, |ewma| {
+            config.reference_latency_ms / (config.reference_latency_ms + ewma)
+        });
+        let reliability = peer.success_rate();
+        let lag = match (peer.last_slot, best_slot) {
+            (Some(slot), Some(best)) => {
+                config.reference_lag_slots / (config.reference_lag_slots + (best - slot) as f64)
+            }
+            _ => 0.0,
+        };
+        let total_weight = config.latency_weight + config.reliability_weight + config.lag_weight;
+        if total_weight <= 0.0 {
+            return 0.0;
+        }
+        100.0
+            * (config.latency_weight * latency
+                + config.reliability_weight * reliability
+                + config.lag_weight * lag)
+            / total_weight
+    }
+
+    fn update(&mut self, name: &str, now_ms: u64, apply: impl FnOnce(&mut PeerReputation)) {
+        let peer = self
+            .peers
+            .entry(name.to_string())
+            .or_insert_with(|| PeerReputation::new(now_ms));
+        apply(peer);
+        let best_slot = self.best_slot();
+        let score = self.score_of(&self.peers[name], best_slot);
+        let config = &self.config;
+        let peer = self.peers.get_mut(name).expect("peer was just inserted");
+        peer.banned = next_ban_state(peer.banned, score, config);
+    }
+
+    fn refresh_bans(&mut self) {
+        let best_slot = self.best_slot();
+        let scores: Vec<(String, f64)> = self
+            .peers
+            .iter()
+            .map(|(name, peer)| (name.clone(), self.score_of(peer, best_slot)))
+            .collect();
+        for (name, score) in scores {
+            let peer = self.peers.get_mut(&name).expect("name came from peers");
+            peer.banned = next_ban_state(peer.banned, score, &self.config);
+        }
+    }
+}
+
+// 밴/해제 문턱을 다르게 두는 히스테리시스. 점수가 문턱 근처에서 흔들려도 상태가 자주 바뀌지 않습니다.
+fn next_ban_state(banned: bool, score: f64, config: &ReputationConfig) -> bool {
+    if banned {
+        score < config.unban_at
+    } else {
+        score < config.ban_below
+    }
+}
+
+// fastest_peer의 평판 버전입니다. 한 번의 latency_ms 대신 누적 점수로 순위를 매기며,
+// 평판 기록이 없거나 밴된 피어는 제외합니다. 순위는 ReputationBook::best_peers를 그대로 따릅니다.
+pub fn best_peers<'a>(peers: &'a [NodePeer], book: &ReputationBook, n: usize) -> Vec<&'a NodePeer> {
+    let by_name: HashMap<&str, &NodePeer> = peers
+        .iter()
+        .map(|peer| (peer.name.as_str(), peer))
+        .collect();
+    book.best_peers(book.len())
+        .into_iter()
+        .filter_map(|(name, _)| by_name.get(name).copied())
+        .take(n)
+        .collect()
+}
@@ -0,0 +1,120 @@
+use std::cmp::Reverse;
+
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::NodePeer;
+
+// fastest_peer는 항상 같은 피어 하나만 고르므로 모든 요청이 한 노드로 몰립니다.
+// 이 트레이트를 구현한 전략들은 요청마다 select를 불러 여러 피어로 요청을 나눕니다.
+// Picks the peer that should serve the next request.
+pub trait PeerSelector {
+    fn select<'a>(&mut self, peers: &'a [NodePeer]) -> Option<&'a NodePeer>;
+}
+
+// 요청을 보내도 되는 피어들입니다. 슬롯을 모르는 피어와 최고 슬롯보다 max_slot_lag 넘게 뒤처진 피어는 뺍니다.
+pub fn eligible_peers(peers: &[NodePeer], max_slot_lag: u64) -> Vec<&NodePeer> {
+    let Some(highest) = peers.iter().filter_map(|peer| peer.last_slot).max() else {
+        return Vec::new();
+    };
+    peers
+        .iter()
+        .filter(|peer| {
+            peer.last_slot
+                .is_some_and(|slot| highest - slot <= max_slot_lag)
+        })
+        .collect()
+}
+
+// 지연 시간이 가장 낮은 피어. 같으면 앞에 있는 피어입니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LowestLatency {
+    pub max_slot_lag: u64,
+}
+
+impl PeerSelector for LowestLatency {
+    fn select<'a>(&mut self, peers: &'a [NodePeer]) -> Option<&'a NodePeer> {
+        eligible_peers(peers, self.max_slot_lag)
+            .into_iter()
+            .min_by_key(|peer| peer.latency_ms)
+    }
+}
+
+// 가장 높은 슬롯을 가진 피어 중 지연 시간이 가장 낮은 피어. 최신 상태가 꼭 필요한 조회에 씁니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct HighestSlot;
+
+impl PeerSelector for HighestSlot {
+    fn select<'a>(&mut self, peers: &'a [NodePeer]) -> Option<&'a NodePeer> {
+        peers
+            .iter()
+            .filter_map(|peer| peer.last_slot.map(|slot| (slot, peer)))
+            .min_by_key(|(slot, peer)| (Reverse(*slot), peer.latency_ms))
+            .map(|(_, peer)| peer)
+    }
+}
+
+// 조건을 만족하는 피어를 차례대로 돌아가며 고릅니다. 피어 목록이 바뀌어도 순번은 이어집니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoundRobin {
+    max_slot_lag: u64,
+    next: usize,
+}
+
+impl RoundRobin {
+    pub fn new(max_slot_lag: u64) -> Self {
+        Self {
+            max_slot_lag,
+            next: 0,
+        }
+    }
+}
+
+impl PeerSelector for RoundRobin {
+    fn select<'a>(&mut self, peers: &'a [NodePeer]) -> Option<&'a NodePeer> {
+        let eligible = eligible_peers(peers, self.max_slot_lag);
+        if eligible.is_empty() {
+            return None;
+        }
+        let peer = eligible[self.next % eligible.len()];
+        self.next = self.next.wrapping_add(1);
+        Some(peer)
+    }
+}
+
+// 지연 시간의 역수에 비례하는 확률로 고릅니다. 빠른 피어가 더 자주 뽑히지만 느린 피어에도 요청이 갑니다.
+// 테스트에서는 seeded로 같은 순서를 재현합니다.
+#[derive(Debug, Clone)]
+pub struct WeightedRandom<R = StdRng> {
+    max_slot_lag: u64,
+    rng: R,
+}
+
+impl WeightedRandom<StdRng> {
+    pub fn seeded(max_slot_lag: u64, seed: u64) -> Self {
+        Self::with_rng(max_slot_lag, StdRng::seed_from_u64(seed))
+    }
+
+    pub fn from_entropy(max_slot_lag: u64) -> Self {
+        Self::with_rng(max_slot_lag, StdRng::from_entropy())
+    }
+}
+
+impl<R: Rng> WeightedRandom<R> {
+    pub fn with_rng(max_slot_lag: u64, rng: R) -> Self {
+        Self { max_slot_lag, rng }
+    }
+}
+
+impl<R: Rng> PeerSelector for WeightedRandom<R> {
+    fn select<'a>(&mut self, peers: &'a [NodePeer]) -> Option<&'a NodePeer> {
+        let eligible = eligible_peers(peers, self.max_slot_lag);
+        // 지연 0ms인 피어도 나눗셈이 되도록 1을 더합니다.
+        let weights = eligible
+            .iter()
+            .map(|peer| 1.0 / (peer.latency_ms as f64 + 1.0));
+        let index = WeightedIndex::new(weights).ok()?;
+        Some(eligible[index.sample(&mut self.rng)])
+    }
+}
@@ -0,0 +1,152 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::NodePeer;
+
+// 지금 쓰는 스냅샷 형식 버전. 형식이 바뀌면 올리고, 예전 버전을 읽는 코드를 load에 추가합니다.
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+#[derive(Debug, thiserror::Error)]
+pub enum SnapshotError {
+    #[error("failed to read or write snapshot: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("malformed snapshot: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("unsupported snapshot version {found} (this build reads version {supported})")]
+    UnsupportedVersion { found: u32, supported: u32 },
+}
+
+// 어느 시점의 피어 상태를 통째로 저장한 것입니다. 사고 리뷰에서 두 시점을 비교할 때 씁니다.
+// A versioned, serde-friendly record of every peer at one point in time.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PeerSnapshot {
+    pub version: u32,
+    pub taken_at_ms: u64,
+    pub peers: Vec<NodePeer>,
+}
+
+// 본문을 읽기 전에 버전만 먼저 확인하기 위한 머리 부분
+#[derive(Deserialize)]
+struct SnapshotHeader {
+    version: u32,
+}
+
+impl PeerSnapshot {
+    pub fn new(taken_at_ms: u64, peers: Vec<NodePeer>) -> Self {
+        Self {
+            version: SNAPSHOT_VERSION,
+            taken_at_ms,
+            peers,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("PeerSnapshot always serializes")
+    }
+
+    // 모르는 버전이면 본문 형식이 달라도 파싱 오류 대신 UnsupportedVersion을 돌려줍니다.
+    pub fn from_json(text: &str) -> Result<Self, SnapshotError> {
+        let header: SnapshotHeader = serde_json::from_str(text)?;
+        if header.version != SNAPSHOT_VERSION {
+            return Err(SnapshotError::UnsupportedVersion {
+                found: header.version,
+                supported: SNAPSHOT_VERSION,
+            });
+        }
+        Ok(serde_json::from_str(text)?)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), SnapshotError> {
+        std::fs::write(path, self.to_json())?;
+        Ok(())
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, SnapshotError> {
+        Self::from_json(&std::fs::read_to_string(path)?)
+    }
+
+    // self(이전)에서 later(이후)로 무엇이 바뀌었는지 계산합니다. 피어는 이름으로 짝짓습니다.
+    pub fn diff(&self, later: &PeerSnapshot) -> SnapshotDiff {
+        let before: HashMap<&str, &NodePeer> = self
+            .peers
+            .iter()
+            .map(|peer| (peer.name.as_str(), peer))
+            .collect();
+        let after: HashMap<&str, &NodePeer> = later
+            .peers
+            .iter()
+            .map(|peer| (peer.name.as_str(), peer))
+            .collect();
+
+        let mut diff = SnapshotDiff {
+            elapsed_ms: later.taken_at_ms.saturating_sub(self.taken_at_ms),
+            ..SnapshotDiff::default()
+        };
+        for peer in &later.peers {
+            match before.get(peer.name.as_str()) {
+                None => diff.added.push(peer.clone()),
+                Some(previous) if *previous != peer => diff.changed.push(PeerChange {
+                    name: peer.name.clone(),
+                    slot_before: previous.last_slot,
+                    slot_after: peer.last_slot,
+                    latency_before_ms: previous.latency_ms,
+                    latency_after_ms: peer.latency_ms,
+                }),
+                Some(_) => {}
+            }
+        }
+        diff.removed = self
+            .peers
+            .iter()
+            .filter(|peer| !after.contains_key(peer.name.as_str()))
+            .cloned()
+            .collect();
+        diff
+    }
+}
+
+// 두 시점 사이에 슬롯이나 지연 시간이 바뀐 피어
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PeerChange {
+    pub name: String,
+    pub slot_before: Option<u64>,
+    pub slot_after: Option<u64>,
+    pub latency_before_ms: u64,
+    pub latency_after_ms: u64,
+}
+
+impl PeerChange {
+    // 두 시점 모두 슬롯을 알 때만 진행한 슬롯 수를 계산합니다. 음수면 슬롯이 뒤로 갔습니다(재시작, 리오그 등).
+    pub fn slot_progress(&self) -> Option<i64> {
+        let (before, after) = self.slot_before.zip(self.slot_after)?;
+        Some(signed_delta(before, after))
+    }
+
+    // 양수면 느려졌습니다.
+    pub fn latency_change_ms(&self) -> i64 {
+        signed_delta(self.latency_before_ms, self.latency_after_ms)
+    }
+}
+
+// u64끼리의 차이는 i64를 넘을 수 있으므로 i128로 계산한 뒤 i64 범위로 포화시킵니다.
+fn signed_delta(before: u64, after: u64) -> i64 {
+    let delta = after as i128 - before as i128;
+    delta.clamp(i64::MIN as i128, i64::MAX as i128) as i64
+}
+
+// 두 스냅샷의 차이. added/changed는 이후 스냅샷 순서, removed는 이전 스냅샷 순서입니다.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SnapshotDiff {
+    pub elapsed_ms: u64,
+    pub added: Vec<NodePeer>,
+    pub removed: Vec<NodePeer>,
+    pub changed: Vec<PeerChange>,
+}
+
+impl SnapshotDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
@@ -0,0 +1,69 @@
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+use day4_node_dashboard::{NodePeer, render_report};
+use day5_mempool_pipeline::PendingTx;
+
+const PEERS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/peers.json");
+const MEMPOOL: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/mempool.json");
+
+fn run(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_node-dashboard"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("node-dashboard binary must start");
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn expected_report() -> String {
+    let peers: Vec<NodePeer> = serde_json::from_str(include_str!("fixtures/peers.json")).unwrap();
+    let mempool: Vec<PendingTx> =
+        serde_json::from_str(include_str!("fixtures/mempool.json")).unwrap();
+    render_report(&peers, &mempool)
+}
+
+#[test]
+// 파일 두 개를 읽어 render_report와 같은 내용을 출력한다.
+fn renders_report_from_files() {
+    let output = run(&["--peers", PEERS, "--mempool", MEMPOOL], "");
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), expected_report());
+}
+
+#[test]
+// "-"는 표준 입력에서 읽는다.
+fn reads_peers_from_stdin() {
+    let output = run(
+        &["--peers", "-", "--mempool", MEMPOOL],
+        include_str!("fixtures/peers.json"),
+    );
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), expected_report());
+}
+
+#[test]
+// 잘못된 인자는 사용법과 함께 종료 코드 2, 읽기/파싱 실패는 종료 코드 1이다.
+fn reports_usage_and_input_errors() {
+    let output = run(&["--mempool", MEMPOOL], "");
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.starts_with("--peers is required\nusage: node-dashboard"));
+
+    // watch 모드는 입력을 다시 읽어야 하므로 표준 입력과 함께 쓸 수 없음
+    let output = run(&["--peers", "-", "--watch", "1"], "[]");
+    assert_eq!(output.status.code(), Some(2));
+
+    let output = run(&["--peers", "-"], "not json");
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.starts_with("failed to parse stdin:"));
+}
@@ -0,0 +1,154 @@
+use day4_node_dashboard::{
+    HealthThresholds, HealthVerdict, LagBucket, NodePeer, PeerLag, PeerRegistry, network_health,
+};
+
+fn peer(name: &str, last_slot: Option<u64>) -> NodePeer {
+    NodePeer {
+        name: name.to_string(),
+        last_slot,
+        latency_ms: 10,
+    }
+}
+
+fn thresholds() -> HealthThresholds {
+    HealthThresholds {
+        synced_max_lag: 2,
+        catching_up_max_lag: 20,
+        min_initialized_peers: 2,
+        lag_buckets: vec![0, 5],
+    }
+}
+
+#[test]
+// 최고 슬롯, 중앙값, 피어별 지연, 지연 구간 분포를 계산한다.
+fn computes_slots_lags_and_buckets() {
+    let peers = vec![
+        peer("Alice", Some(100)),
+        peer("Bob", Some(97)),
+        peer("Charlie", None),
+        peer("David", Some(80)),
+        peer("Eve", Some(100)),
+    ];
+    let health = network_health(&peers, &thresholds());
+
+    assert_eq!(health.highest_slot, Some(100));
+    // 80, 97, 100, 100 → 짝수 개라 아래쪽 중앙값 97
+    assert_eq!(health.median_slot, Some(97));
+    assert_eq!(health.uninitialized, 1);
+    assert_eq!(
+        health.lags[..3],
+        [
+            PeerLag {
+                name: "Alice".to_string(),
+                lag: Some(0)
+            },
+            PeerLag {
+                name: "Bob".to_string(),
+                lag: Some(3)
+            },
+            PeerLag {
+                name: "Charlie".to_string(),
+                lag: None
+            },
+        ]
+    );
+    assert_eq!(
+        health.buckets,
+        vec![
+            LagBucket {
+                min_lag: 0,
+                max_lag: Some(0),
+                peers: 2
+            },
+            LagBucket {
+                min_lag: 1,
+                max_lag: Some(5),
+                peers: 1
+            },
+            LagBucket {
+                min_lag: 6,
+                max_lag: None,
+                peers: 1
+            },
+        ]
+    );
+    // 중앙값 지연 3 → 2 초과, 20 이하
+    assert_eq!(health.verdict, HealthVerdict::CatchingUp);
+    assert!(health.verdict.needs_alert());
+}
+
+#[test]
+// 중앙값 피어의 지연에 따라 Synced / CatchingUp / Stalled로 판정한다.
+fn verdict_follows_median_lag_thresholds() {
+    let verdict = |slots: &[u64]| {
+        let peers: Vec<NodePeer> = slots
+            .iter()
+            .enumerate()
+            .map(|(i, &slot)| peer(&format!("p{i}"), Some(slot)))
+            .collect();
+        network_health(&peers, &thresholds()).verdict
+    };
+
+    // 한 피어만 크게 뒤처져도 다수가 최신이면 Synced
+    assert_eq!(verdict(&[100, 99, 98, 10]), HealthVerdict::Synced);
+    assert_eq!(verdict(&[100, 90, 85]), HealthVerdict::CatchingUp);
+    // 한 피어만 앞서 나가고 다수가 멈춰 있으면 Stalled
+    assert_eq!(verdict(&[500, 100, 100]), HealthVerdict::Stalled);
+    assert!(!HealthVerdict::Synced.needs_alert());
+}
+
+#[test]
+// 슬롯을 아는 피어가 너무 적으면 Isolated다.
+fn too_few_initialized_peers_is_isolated() {
+    let peers = vec![peer("Alice", Some(100)), peer("Bob", None)];
+    let health = network_health(&peers, &thresholds());
+    assert_eq!(health.verdict, HealthVerdict::Isolated);
+
+    let empty = network_health(&[], &HealthThresholds::default());
+    assert_eq!(empty.highest_slot, None);
+    assert_eq!(empty.median_slot, None);
+    assert_eq!(empty.verdict, HealthVerdict::Isolated);
+    assert!(empty.buckets.iter().all(|bucket| bucket.peers == 0));
+}
+
+#[test]
+// 레지스트리에서도 같은 요약을 바로 얻는다.
+fn registry_exposes_network_health() {
+    let mut registry = PeerRegistry::new();
+    registry.add(peer("Alice", Some(100)), 0).unwrap();
+    registry.add(peer("Bob", Some(99)), 0).unwrap();
+
+    let health = registry.network_health(&thresholds());
+    assert_eq!(health.verdict, HealthVerdict::Synced);
+    assert_eq!(health, network_health(registry.peers(), &thresholds()));
+}
+
+#[test]
+// 상한이 뒤섞이거나 중복되거나 u64::MAX여도 구간이 뒤집히거나 넘치지 않는다.
+fn lag_buckets_are_sorted_and_saturate_at_max() {
+    let peers = vec![peer("Alice", Some(100)), peer("Bob", Some(90))];
+    let mut thresholds = thresholds();
+    thresholds.lag_buckets = vec![u64::MAX, 5, 0, 5];
+    let health = network_health(&peers, &thresholds);
+
+    assert_eq!(
+        health.buckets,
+        vec![
+            LagBucket {
+                min_lag: 0,
+                max_lag: Some(0),
+                peers: 1,
+            },
+            LagBucket {
+                min_lag: 1,
+                max_lag: Some(5),
+                peers: 0,
+            },
+            LagBucket {
+                min_lag: 6,
+                max_lag: Some(u64::MAX),
+                peers: 1,
+            },
+        ]
+    );
+}
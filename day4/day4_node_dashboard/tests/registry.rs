@@ -0,0 +1,117 @@
+use day4_node_dashboard::{
+    NodePeer, PeerRegistry, PeerUpdate, RegistryError, count_uninitialized, summarize_slots,
+};
+
+fn peer(name: &str, last_slot: Option<u64>, latency_ms: u64) -> NodePeer {
+    NodePeer {
+        name: name.to_string(),
+        last_slot,
+        latency_ms,
+    }
+}
+
+fn registry() -> PeerRegistry {
+    let mut registry = PeerRegistry::new();
+    registry.add(peer("Alice", Some(100), 50), 0).unwrap();
+    registry.add(peer("Bob", None, 30), 0).unwrap();
+    registry.add(peer("Charlie", Some(200), 80), 0).unwrap();
+    registry
+}
+
+#[test]
+// 이벤트가 피어 상태와 마지막 소식 시각을 갱신한다.
+fn updates_change_peer_state_and_last_seen() {
+    let mut registry = registry();
+
+    registry
+        .apply("Bob", PeerUpdate::SlotReported(150), 1_000)
+        .unwrap();
+    registry
+        .apply("Charlie", PeerUpdate::LatencySample(10), 2_000)
+        .unwrap();
+
+    assert_eq!(registry.get("Bob").unwrap().last_slot, Some(150));
+    assert_eq!(registry.get("Charlie").unwrap().latency_ms, 10);
+    assert_eq!(registry.last_seen("Alice"), Some(0));
+    assert_eq!(registry.last_seen("Bob"), Some(1_000));
+    assert_eq!(registry.last_seen("Charlie"), Some(2_000));
+}
+
+#[test]
+// 중복 등록과 모르는 피어에 대한 이벤트는 오류로 돌려준다.
+fn rejects_duplicates_and_unknown_peers() {
+    let mut registry = registry();
+
+    assert_eq!(
+        registry.add(peer("Alice", None, 1), 5),
+        Err(RegistryError::AlreadyRegistered {
+            name: "Alice".to_string()
+        })
+    );
+    assert_eq!(
+        registry.apply("Nobody", PeerUpdate::LatencySample(1), 5),
+        Err(RegistryError::UnknownPeer {
+            name: "Nobody".to_string()
+        })
+    );
+    // 기존 Alice는 그대로
+    assert_eq!(registry.get("Alice").unwrap().latency_ms, 50);
+    assert_eq!(registry.len(), 3);
+}
+
+#[test]
+// 연결이 끊긴 피어는 빠지고, 남은 피어들의 순서와 조회는 유지된다.
+fn disconnect_removes_peer_and_keeps_order() {
+    let mut registry = registry();
+
+    let removed = registry
+        .apply("Alice", PeerUpdate::Disconnected, 10)
+        .unwrap();
+    assert_eq!(removed.unwrap().name, "Alice");
+    assert!(registry.get("Alice").is_none());
+    assert_eq!(registry.get("Charlie").unwrap().last_slot, Some(200));
+    assert_eq!(
+        registry.summarize_slots(),
+        vec!["Bob awaiting first block", "Charlie synced up to slot 200"]
+    );
+}
+
+#[test]
+// 타임아웃보다 오래 조용한 피어만 정리된다.
+fn expires_silent_peers() {
+    let mut registry = registry();
+    registry
+        .apply("Bob", PeerUpdate::LatencySample(25), 9_000)
+        .unwrap();
+
+    let expired = registry.expire_silent(10_000, 5_000);
+    let names: Vec<&str> = expired.iter().map(|peer| peer.name.as_str()).collect();
+    assert_eq!(names, vec!["Alice", "Charlie"]);
+    assert_eq!(registry.len(), 1);
+    assert_eq!(registry.last_seen("Bob"), Some(9_000));
+
+    // 딱 타임아웃만큼 지난 피어는 아직 살아 있음
+    assert!(registry.expire_silent(14_000, 5_000).is_empty());
+}
+
+#[test]
+// 기존 슬라이스 함수들이 레지스트리 위의 뷰로 그대로 동작한다.
+fn slice_functions_work_as_views() {
+    let mut registry = registry();
+    assert_eq!(registry.count_uninitialized(), 1);
+    assert_eq!(registry.fastest_peer().unwrap().name, "Bob");
+
+    registry
+        .apply("Bob", PeerUpdate::SlotReported(120), 1)
+        .unwrap();
+    registry
+        .apply("Alice", PeerUpdate::LatencySample(5), 1)
+        .unwrap();
+
+    assert_eq!(count_uninitialized(registry.peers()), 0);
+    assert_eq!(registry.fastest_peer().unwrap().name, "Alice");
+    assert_eq!(
+        summarize_slots(registry.peers())[1],
+        "Bob synced up to slot 120"
+    );
+}
@@ -0,0 +1,129 @@
+use day4_node_dashboard::{
+    CsvRenderer, DashboardRenderer, JsonRenderer, NodePeer, PrometheusRenderer, TableRenderer,
+};
+
+fn peers() -> Vec<NodePeer> {
+    vec![
+        NodePeer {
+            name: "Alice".to_string(),
+            last_slot: Some(100),
+            latency_ms: 50,
+        },
+        NodePeer {
+            name: "Bob".to_string(),
+            last_slot: None,
+            latency_ms: 30,
+        },
+        NodePeer {
+            name: "Charlie".to_string(),
+            last_slot: Some(200),
+            latency_ms: 180,
+        },
+    ]
+}
+
+// 여러 형식을 트레이트 객체로 섞어 쓸 수 있다.
+fn render_all(peers: &[NodePeer]) -> Vec<String> {
+    let renderers: Vec<Box<dyn DashboardRenderer>> = vec![
+        Box::new(JsonRenderer),
+        Box::new(CsvRenderer),
+        Box::new(PrometheusRenderer),
+        Box::new(TableRenderer),
+    ];
+    renderers
+        .iter()
+        .map(|renderer| renderer.render(peers))
+        .collect()
+}
+
+#[test]
+// JSON 출력 스냅샷. 슬롯을 모르는 피어는 null이다.
+fn json_snapshot() {
+    assert_eq!(
+        JsonRenderer.render(&peers()),
+        r#"[
+  {
+    "name": "Alice",
+    "last_slot": 100,
+    "latency_ms": 50
+  },
+  {
+    "name": "Bob",
+    "last_slot": null,
+    "latency_ms": 30
+  },
+  {
+    "name": "Charlie",
+    "last_slot": 200,
+    "latency_ms": 180
+  }
+]"#
+    );
+}
+
+#[test]
+// JSON 출력은 다시 NodePeer로 읽을 수 있다.
+fn json_round_trips() {
+    let parsed: Vec<NodePeer> = serde_json::from_str(&JsonRenderer.render(&peers())).unwrap();
+    assert_eq!(parsed, peers());
+}
+
+#[test]
+// CSV 출력 스냅샷. 특수 문자가 든 이름은 따옴표로 감싼다.
+fn csv_snapshot() {
+    let mut peers = peers();
+    peers[1].name = "Bob, \"the builder\"".to_string();
+    assert_eq!(
+        CsvRenderer.render(&peers),
+        "name,last_slot,latency_ms\n\
+         Alice,100,50\n\
+         \"Bob, \"\"the builder\"\"\",,30\n\
+         Charlie,200,180\n"
+    );
+}
+
+#[test]
+// Prometheus 출력 스냅샷. 슬롯을 모르는 피어는 last_slot 지표에서 빠진다.
+fn prometheus_snapshot() {
+    assert_eq!(
+        PrometheusRenderer.render(&peers()),
+        "# HELP node_peer_last_slot Last slot reported by the peer.\n\
+         # TYPE node_peer_last_slot gauge\n\
+         node_peer_last_slot{peer=\"Alice\"} 100\n\
+         node_peer_last_slot{peer=\"Charlie\"} 200\n\
+         # HELP node_peer_latency_ms Round-trip latency to the peer in milliseconds.\n\
+         # TYPE node_peer_latency_ms gauge\n\
+         node_peer_latency_ms{peer=\"Alice\"} 50\n\
+         node_peer_latency_ms{peer=\"Bob\"} 30\n\
+         node_peer_latency_ms{peer=\"Charlie\"} 180\n\
+         # HELP node_peers_uninitialized Peers that have not reported a slot yet.\n\
+         # TYPE node_peers_uninitialized gauge\n\
+         node_peers_uninitialized 1\n"
+    );
+}
+
+#[test]
+// 표 출력 스냅샷. 열 너비는 가장 긴 값에 맞춰진다.
+fn table_snapshot() {
+    assert_eq!(
+        TableRenderer.render(&peers()),
+        "PEER     LAST SLOT  LATENCY MS\n\
+         -------  ---------  ----------\n\
+         Alice          100          50\n\
+         Bob              -          30\n\
+         Charlie        200         180\n"
+    );
+}
+
+#[test]
+// 피어가 없어도 헤더와 집계는 나온다.
+fn empty_peer_list() {
+    let outputs = render_all(&[]);
+    assert_eq!(outputs[0], "[]");
+    assert_eq!(outputs[1], "name,last_slot,latency_ms\n");
+    assert!(outputs[2].ends_with("node_peers_uninitialized 0\n"));
+    assert_eq!(
+        outputs[3],
+        "PEER  LAST SLOT  LATENCY MS\n----  ---------  ----------\n"
+    );
+}
@@ -0,0 +1,45 @@
+use day4_node_dashboard::{NodePeer, render_report};
+use day5_mempool_pipeline::PendingTx;
+
+fn fixtures() -> (Vec<NodePeer>, Vec<PendingTx>) {
+    let peers = serde_json::from_str(include_str!("fixtures/peers.json")).unwrap();
+    let mempool = serde_json::from_str(include_str!("fixtures/mempool.json")).unwrap();
+    (peers, mempool)
+}
+
+#[test]
+// 피어 문장 + 속도 분류, 계정별 멤풀 통계, 스케줄러 대기열을 한 화면에 그린다.
+fn report_snapshot() {
+    let (peers, mempool) = fixtures();
+    assert_eq!(
+        render_report(&peers, &mempool),
+        "\
+== Peers (3) ==
+Alice synced up to slot 100 [Instant, 50 ms]
+Bob awaiting first block [Acceptable, 320 ms]
+Charlie synced up to slot 99 [Lagging, 800 ms]
+network: Synced (highest slot 100, median slot 99, 1 awaiting first block)
+
+== Mempool (4 txs, 2 accounts) ==
+ACCOUNT                                       TXS  PENDING  SIMULATED  REJECTED  TOTAL FEE  BYTES
+--------------------------------------------  ---  -------  ---------  --------  ---------  -----
+CVDFLCAjXhVWiPXH9nTCTpCgVzmDVoiPzNJYuccr1dqB    2        1          1         0      14000    500
+DdqGmK5uamYN5vmuZrzpQhKeehLdwtPLVJdhu5P2iJKC    2        1          0         1       7000    600
+
+== Scheduler (2 queued, 1 skipped) ==
+NEXT TX                                       FEE      CU
+-------------------------------------------  ----  ------
+8qbHbw2BbbTHBW1sbeqakYXVKRQM8Ne7pLK7m6CVfeR  9000    1200
+4vJ9JU1bJJE96FWSJKvHsmmFADCg4gpZQff4P3bkLKi  5000  200000
+"
+    );
+}
+
+#[test]
+// 입력이 비어 있어도 각 섹션의 머리글은 나온다.
+fn empty_inputs_render_headers() {
+    let report = render_report(&[], &[]);
+    assert!(report.starts_with("== Peers (0) ==\nnetwork: Isolated"));
+    assert!(report.contains("== Mempool (0 txs, 0 accounts) =="));
+    assert!(report.contains("== Scheduler (0 queued, 0 skipped) =="));
+}
@@ -0,0 +1,133 @@
+use day4_node_dashboard::{NodePeer, ReputationBook, ReputationConfig, best_peers, fastest_peer};
+
+fn peer(name: &str, last_slot: Option<u64>, latency_ms: u64) -> NodePeer {
+    NodePeer {
+        name: name.to_string(),
+        last_slot,
+        latency_ms,
+    }
+}
+
+#[test]
+// EWMA는 최근 샘플 쪽으로 움직이고, 백분위수는 보관 중인 샘플로 계산한다.
+fn latency_history_tracks_ewma_and_percentiles() {
+    let mut book = ReputationBook::new(ReputationConfig {
+        ewma_alpha: 0.5,
+        history_len: 4,
+        ..ReputationConfig::default()
+    });
+    for (t, latency) in [100, 200, 300, 400, 500].into_iter().enumerate() {
+        book.record_latency("Alice", latency, t as u64);
+    }
+
+    let alice = book.get("Alice").unwrap();
+    // 100 → 150 → 225 → 312.5 → 406.25
+    assert_eq!(alice.ewma_latency_ms, Some(406.25));
+    // 최근 4개(200, 300, 400, 500)만 보관
+    assert_eq!(alice.latency_samples(), 4);
+    assert_eq!(alice.latency_percentile(50.0), Some(300));
+    assert_eq!(alice.latency_percentile(90.0), Some(500));
+    assert_eq!(alice.latency_percentile(0.0), Some(200));
+}
+
+#[test]
+// 슬롯 지연은 가장 앞선 피어를 기준으로 계산한다.
+fn slot_lag_is_relative_to_best_peer() {
+    let mut book = ReputationBook::default();
+    book.record_slot("Alice", 100, 0);
+    book.record_slot("Bob", 90, 0);
+    book.record_latency("Carol", 10, 0);
+
+    assert_eq!(book.best_slot(), Some(100));
+    assert_eq!(book.slot_lag("Alice"), Some(0));
+    assert_eq!(book.slot_lag("Bob"), Some(10));
+    assert_eq!(book.slot_lag("Carol"), None);
+    assert_eq!(book.slot_lag("Nobody"), None);
+}
+
+#[test]
+// 단 한 번 빨랐던 피어보다, 꾸준히 성공하고 최신 슬롯을 따라가는 피어가 더 높은 순위를 받는다.
+fn best_peers_prefers_reliable_history_over_one_fast_sample() {
+    let peers = vec![
+        peer("Alice", Some(100), 80),
+        peer("Bob", Some(60), 20),
+        peer("Charlie", None, 50),
+    ];
+    let mut book = ReputationBook::default();
+    for peer in &peers {
+        book.observe(peer, 0);
+    }
+    for t in 1..=10 {
+        book.record_success("Alice", t);
+        book.record_failure("Bob", t);
+    }
+
+    // fastest_peer는 여전히 한 번의 지연 시간만 봄
+    assert_eq!(fastest_peer(&peers).unwrap().name, "Bob");
+
+    let ranked: Vec<&str> = best_peers(&peers, &book, 3)
+        .into_iter()
+        .map(|peer| peer.name.as_str())
+        .collect();
+    assert_eq!(ranked, vec!["Alice", "Charlie", "Bob"]);
+    assert_eq!(best_peers(&peers, &book, 1)[0].name, "Alice");
+}
+
+#[test]
+// 점수가 문턱 아래로 떨어지면 밴되고, 감쇠로 회복해 해제 문턱을 넘어야 풀린다.
+fn ban_and_unban_follow_thresholds_and_decay() {
+    let config = ReputationConfig {
+        latency_weight: 0.0,
+        reliability_weight: 1.0,
+        lag_weight: 0.0,
+        ban_below: 20.0,
+        unban_at: 40.0,
+        half_life_ms: 1_000,
+        ..ReputationConfig::default()
+    };
+    let mut book = ReputationBook::new(config);
+    book.record_success("Alice", 0);
+
+    // 성공률 (0 + 1) / (n + 2) < 0.2 가 되는 4번째 실패에서 밴
+    for _ in 0..3 {
+        book.record_failure("Bob", 0);
+    }
+    assert!(!book.is_banned("Bob"));
+    book.record_failure("Bob", 0);
+    assert!(book.is_banned("Bob"));
+    assert_eq!(book.best_peers(5).len(), 1);
+
+    // 반감기 한 번: 실패 2회 상당 → 1/4 = 25점. 밴 문턱은 넘었지만 해제 문턱(40)은 아직 못 넘음
+    book.decay(1_000);
+    assert!(book.is_banned("Bob"));
+
+    // 반감기 세 번 더: 실패 0.25회 상당 → 1/2.25 ≈ 44점 → 해제
+    book.decay(4_000);
+    assert!(!book.is_banned("Bob"));
+    let names: Vec<&str> = book
+        .best_peers(5)
+        .into_iter()
+        .map(|(name, _)| name)
+        .collect();
+    assert_eq!(names, vec!["Alice", "Bob"]);
+}
+
+#[test]
+// 평판 기록이 없는 피어는 순위에서 빠지고, 점수가 같으면 이름 순이다.
+fn best_peers_skips_unknown_peers_and_breaks_ties_by_name() {
+    let peers = vec![
+        peer("Zed", Some(10), 30),
+        peer("Amy", Some(10), 30),
+        peer("Ghost", Some(10), 1),
+    ];
+    let mut book = ReputationBook::default();
+    book.observe(&peers[0], 0);
+    book.observe(&peers[1], 0);
+
+    let ranked: Vec<&str> = best_peers(&peers, &book, 10)
+        .into_iter()
+        .map(|peer| peer.name.as_str())
+        .collect();
+    assert_eq!(ranked, vec!["Amy", "Zed"]);
+    assert!(best_peers(&peers, &book, 0).is_empty());
+}
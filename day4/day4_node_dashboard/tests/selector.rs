@@ -0,0 +1,101 @@
+use std::collections::HashMap;
+
+use day4_node_dashboard::{
+    HighestSlot, LowestLatency, NodePeer, PeerSelector, RoundRobin, WeightedRandom, eligible_peers,
+};
+
+fn peer(name: &str, last_slot: Option<u64>, latency_ms: u64) -> NodePeer {
+    NodePeer {
+        name: name.to_string(),
+        last_slot,
+        latency_ms,
+    }
+}
+
+// Dave는 슬롯을 모르고, Erin은 최고 슬롯보다 100 슬롯 뒤처져 있습니다.
+fn peers() -> Vec<NodePeer> {
+    vec![
+        peer("Alice", Some(1_000), 120),
+        peer("Bob", Some(998), 40),
+        peer("Charlie", Some(1_000), 80),
+        peer("Dave", None, 5),
+        peer("Erin", Some(900), 10),
+    ]
+}
+
+fn names<'a>(picks: &[Option<&'a NodePeer>]) -> Vec<&'a str> {
+    picks
+        .iter()
+        .map(|peer| peer.map_or("-", |peer| peer.name.as_str()))
+        .collect()
+}
+
+#[test]
+// 슬롯을 모르거나 너무 뒤처진 피어는 후보에서 빠진다.
+fn eligible_peers_skip_unknown_and_lagging() {
+    let peers = peers();
+    let eligible: Vec<&str> = eligible_peers(&peers, 16)
+        .iter()
+        .map(|peer| peer.name.as_str())
+        .collect();
+    assert_eq!(eligible, vec!["Alice", "Bob", "Charlie"]);
+    assert!(eligible_peers(&[peer("Dave", None, 5)], 16).is_empty());
+}
+
+#[test]
+// 가장 빠른 피어라도 뒤처져 있으면 고르지 않는다.
+fn lowest_latency_ignores_ineligible_peers() {
+    let peers = peers();
+    let mut selector = LowestLatency { max_slot_lag: 16 };
+    assert_eq!(selector.select(&peers).unwrap().name, "Bob");
+
+    let mut strict = LowestLatency { max_slot_lag: 0 };
+    assert_eq!(strict.select(&peers).unwrap().name, "Charlie");
+    assert!(selector.select(&[]).is_none());
+}
+
+#[test]
+// 최고 슬롯 피어 중 가장 빠른 피어를 고른다.
+fn highest_slot_breaks_ties_by_latency() {
+    let peers = peers();
+    assert_eq!(HighestSlot.select(&peers).unwrap().name, "Charlie");
+    assert!(HighestSlot.select(&[peer("Dave", None, 5)]).is_none());
+}
+
+#[test]
+// 라운드 로빈은 후보 피어를 차례로 돌며, 목록이 바뀌어도 계속 돈다.
+fn round_robin_spreads_requests() {
+    let peers = peers();
+    let mut selector = RoundRobin::new(16);
+    let picks: Vec<Option<&NodePeer>> = (0..4).map(|_| selector.select(&peers)).collect();
+    assert_eq!(names(&picks), vec!["Alice", "Bob", "Charlie", "Alice"]);
+
+    // Bob이 멀리 뒤처지면 남은 두 피어만 돈다.
+    let mut lagging = peers.clone();
+    lagging[1].last_slot = Some(500);
+    let picks: Vec<Option<&NodePeer>> = (0..2).map(|_| selector.select(&lagging)).collect();
+    assert_eq!(names(&picks), vec!["Alice", "Charlie"]);
+}
+
+#[test]
+// 같은 시드는 같은 순서를 만들고, 빠른 피어가 더 자주 뽑히지만 모든 후보가 요청을 받는다.
+fn weighted_random_is_reproducible_and_favours_fast_peers() {
+    let peers = peers();
+    let run = |seed| {
+        let mut selector = WeightedRandom::seeded(16, seed);
+        (0..2_000)
+            .map(|_| selector.select(&peers).unwrap().name.clone())
+            .collect::<Vec<_>>()
+    };
+    let first = run(7);
+    assert_eq!(first, run(7));
+
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for name in &first {
+        *counts.entry(name.as_str()).or_default() += 1;
+    }
+    assert_eq!(counts.len(), 3);
+    assert!(!counts.contains_key("Dave") && !counts.contains_key("Erin"));
+    assert!(counts["Bob"] > counts["Charlie"]);
+    assert!(counts["Charlie"] > counts["Alice"]);
+}
@@ -0,0 +1,125 @@
+use std::path::PathBuf;
+
+use day4_node_dashboard::{NodePeer, PeerChange, PeerSnapshot, SNAPSHOT_VERSION, SnapshotError};
+
+fn peer(name: &str, last_slot: Option<u64>, latency_ms: u64) -> NodePeer {
+    NodePeer {
+        name: name.to_string(),
+        last_slot,
+        latency_ms,
+    }
+}
+
+fn temp_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join(name)
+}
+
+#[test]
+// 저장한 스냅샷을 그대로 다시 읽는다.
+fn save_and_load_round_trip() {
+    let snapshot = PeerSnapshot::new(
+        1_000,
+        vec![peer("Alice", Some(100), 50), peer("Bob", None, 30)],
+    );
+    let path = temp_path("snapshot_round_trip.json");
+    snapshot.save(&path).unwrap();
+
+    let loaded = PeerSnapshot::load(&path).unwrap();
+    assert_eq!(loaded, snapshot);
+    assert_eq!(loaded.version, SNAPSHOT_VERSION);
+}
+
+#[test]
+// 모르는 버전과 깨진 파일은 서로 다른 오류로 알려 준다.
+fn load_rejects_unknown_versions() {
+    let future = r#"{"version": 99, "taken_at_ms": 0, "peers": {"format": "changed"}}"#;
+    assert!(matches!(
+        PeerSnapshot::from_json(future),
+        Err(SnapshotError::UnsupportedVersion {
+            found: 99,
+            supported: SNAPSHOT_VERSION
+        })
+    ));
+    assert!(matches!(
+        PeerSnapshot::from_json(r#"{"version": 1, "peers": []}"#),
+        Err(SnapshotError::Json(_))
+    ));
+    assert!(matches!(
+        PeerSnapshot::load(temp_path("missing_snapshot.json")),
+        Err(SnapshotError::Io(_))
+    ));
+}
+
+#[test]
+// 추가/제거된 피어와 슬롯, 지연 시간 변화를 계산한다.
+fn diff_reports_membership_slot_and_latency_changes() {
+    let before = PeerSnapshot::new(
+        1_000,
+        vec![
+            peer("Alice", Some(100), 50),
+            peer("Bob", None, 30),
+            peer("Charlie", Some(90), 80),
+            peer("Dave", Some(100), 20),
+        ],
+    );
+    let after = PeerSnapshot::new(
+        6_000,
+        vec![
+            peer("Erin", Some(110), 15),
+            peer("Alice", Some(112), 50),
+            peer("Bob", Some(105), 30),
+            peer("Dave", Some(100), 45),
+        ],
+    );
+
+    let diff = before.diff(&after);
+    assert_eq!(diff.elapsed_ms, 5_000);
+    assert_eq!(diff.added, vec![peer("Erin", Some(110), 15)]);
+    assert_eq!(diff.removed, vec![peer("Charlie", Some(90), 80)]);
+
+    let changes: Vec<(&str, Option<i64>, i64)> = diff
+        .changed
+        .iter()
+        .map(|change| {
+            (
+                change.name.as_str(),
+                change.slot_progress(),
+                change.latency_change_ms(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        changes,
+        vec![
+            ("Alice", Some(12), 0),
+            ("Bob", None, 0),
+            ("Dave", Some(0), 25)
+        ]
+    );
+
+    assert!(after.diff(&after).is_empty());
+}
+
+#[test]
+// u64 끝값끼리의 차이도 넘치지 않고 i64 범위로 포화된다.
+fn peer_change_deltas_saturate_for_extreme_values() {
+    let change = PeerChange {
+        name: "Alice".to_string(),
+        slot_before: Some(u64::MAX),
+        slot_after: Some(i64::MAX as u64),
+        latency_before_ms: 0,
+        latency_after_ms: u64::MAX,
+    };
+    assert_eq!(change.slot_progress(), Some(i64::MIN));
+    assert_eq!(change.latency_change_ms(), i64::MAX);
+
+    let change = PeerChange {
+        slot_before: Some(0),
+        slot_after: Some(u64::MAX),
+        latency_before_ms: u64::MAX,
+        latency_after_ms: u64::MAX - 5,
+        ..change
+    };
+    assert_eq!(change.slot_progress(), Some(i64::MAX));
+    assert_eq!(change.latency_change_ms(), -5);
+}
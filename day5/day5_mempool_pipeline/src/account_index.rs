@@ -0,0 +1,158 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use crate::ranking::rank;
+use crate::{AccountId, AccountStats, PendingTx, StatsMetric, TxId, TxStatus};
+
+// 계정 하나에 대해 인덱스가 유지하는 상태입니다.
+// 최솟값/최댓값/가장 오래된 시각은 빼기로 되돌릴 수 없어서, 정렬된 집합을 함께 들고 다니며
+// 트랜잭션이 빠질 때마다 남은 것 중 첫/마지막 원소로 다시 채웁니다.
+#[derive(Default)]
+struct AccountEntry {
+    stats: AccountStats,
+    // (수수료, id) 순으로 정렬된 소속 트랜잭션. 비면 계정을 지웁니다.
+    fees: BTreeSet<(u64, TxId)>,
+    // (수신 시각, id) 순으로 정렬된 Pending 트랜잭션
+    pending_since: BTreeSet<(u64, TxId)>,
+    // (nonce, id) 순으로 정렬된 소속 트랜잭션. 축출할 때 nonce가 가장 큰 것부터 고릅니다.
+    nonces: BTreeSet<(u64, TxId)>,
+}
+
+impl AccountEntry {
+    fn add(&mut self, tx: &PendingTx) {
+        self.stats.record(tx);
+        self.fees.insert((tx.fee_micro_lamports, tx.id));
+        self.nonces.insert((tx.nonce, tx.id));
+        if let TxStatus::Pending = tx.status {
+            self.pending_since.insert((tx.received_at_ms, tx.id));
+        }
+    }
+
+    fn subtract(&mut self, tx: &PendingTx) {
+        self.stats.forget(tx);
+        self.fees.remove(&(tx.fee_micro_lamports, tx.id));
+        self.nonces.remove(&(tx.nonce, tx.id));
+        self.pending_since.remove(&(tx.received_at_ms, tx.id));
+        self.refresh_extremes();
+    }
+
+    fn refresh_extremes(&mut self) {
+        self.stats.min_fee = self.fees.first().map(|(fee, _)| *fee);
+        self.stats.max_fee = self.fees.last().map(|(fee, _)| *fee);
+        self.stats.oldest_pending_at_ms = self.pending_since.first().map(|(at, _)| *at);
+    }
+}
+
+// 멤풀 트랜잭션을 소유하면서 계정별 통계를 증분으로 갱신하는 인덱스입니다.
+// It owns the pending transactions and keeps per-account stats up to date on every change,
+// so dashboards can read stats without regrouping or cloning the whole pool.
+#[derive(Default)]
+pub struct AccountIndex {
+    // id → 트랜잭션 원본. 인덱스가 소유하므로 group_by_account처럼 복사본을 만들 필요가 없습니다.
+    txs: HashMap<TxId, PendingTx>,
+    // 계정 → 누적 통계. BTreeMap이라 compute_account_stats와 같은 사전식 순서로 순회됩니다.
+    accounts: BTreeMap<AccountId, AccountEntry>,
+    // 풀 전체 페이로드 바이트 합계. 전역 바이트 예산 검사에 씁니다.
+    total_bytes: u64,
+}
+
+impl AccountIndex {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // 트랜잭션을 추가하고 계정 통계에 기여분을 더합니다.
+    // 같은 id가 이미 있으면 기존 트랜잭션의 기여분을 먼저 빼고 교체한 뒤, 이전 값을 돌려줍니다.
+    pub fn insert(&mut self, tx: PendingTx) -> Option<PendingTx> {
+        let replaced = self.remove(&tx.id);
+        self.accounts.entry(tx.account).or_default().add(&tx);
+        self.total_bytes += tx.payload_size as u64;
+        self.txs.insert(tx.id, tx);
+        replaced
+    }
+
+    // 트랜잭션을 제거하고 계정 통계에서 기여분을 뺍니다.
+    // 계정의 마지막 트랜잭션이 빠지면 계정 항목도 함께 사라집니다.
+    pub fn remove(&mut self, id: &TxId) -> Option<PendingTx> {
+        let tx = self.txs.remove(id)?;
+        self.total_bytes -= tx.payload_size as u64;
+        if let Some(entry) = self.accounts.get_mut(&tx.account) {
+            entry.subtract(&tx);
+            if entry.fees.is_empty() {
+                self.accounts.remove(&tx.account);
+            }
+        }
+        Some(tx)
+    }
+
+    // 트랜잭션 상태만 바꿉니다. 이전 상태의 기여분을 빼고 새 상태로 다시 더해 통계를 맞춥니다.
+    // 알 수 없는 id면 None, 성공하면 이전 상태를 돌려줍니다.
+    pub fn update_status(&mut self, id: &TxId, status: TxStatus) -> Option<TxStatus> {
+        let tx = self.txs.get_mut(id)?;
+        let entry = self
+            .accounts
+            .get_mut(&tx.account)
+            .expect("indexed transaction must have an account entry");
+        entry.subtract(tx);
+        let previous = std::mem::replace(&mut tx.status, status);
+        entry.add(tx);
+        Some(previous)
+    }
+
+    pub fn get(&self, id: &TxId) -> Option<&PendingTx> {
+        self.txs.get(id)
+    }
+
+    pub fn account_stats(&self, account: &AccountId) -> Option<&AccountStats> {
+        self.accounts.get(account).map(|entry| &entry.stats)
+    }
+
+    // 계정 id 순으로 통계를 빌려줍니다. 트랜잭션은 전혀 순회하지 않습니다.
+    pub fn stats(&self) -> impl Iterator<Item = (&AccountId, &AccountStats)> {
+        self.accounts
+            .iter()
+            .map(|(account, entry)| (account, &entry.stats))
+    }
+
+    // compute_account_stats와 같은 모양의 결과가 필요할 때 사용합니다. 통계만 복사합니다.
+    pub fn snapshot(&self) -> BTreeMap<AccountId, AccountStats> {
+        self.accounts
+            .iter()
+            .map(|(account, entry)| (*account, entry.stats.clone()))
+            .collect()
+    }
+
+    // 지표 값이 큰 계정부터 최대 n개. 값이 같으면 계정 id 바이트 순이며, ranking::top_accounts와 같은 규칙입니다.
+    pub fn top_accounts(&self, metric: StatsMetric, n: usize) -> Vec<(&AccountId, &AccountStats)> {
+        rank(self.stats(), metric, n)
+    }
+
+    // 계정의 트랜잭션을 nonce가 큰 것부터 돌려줍니다. 축출 후보를 고를 때 씁니다.
+    // 맨 뒤 nonce부터 빼야 남은 트랜잭션 사이에 nonce 빈틈이 생기지 않습니다.
+    pub fn highest_nonce_first(&self, account: &AccountId) -> impl Iterator<Item = &PendingTx> {
+        self.accounts
+            .get(account)
+            .into_iter()
+            .flat_map(|entry| entry.nonces.iter().rev())
+            .filter_map(|(_, id)| self.txs.get(id))
+    }
+
+    pub fn total_bytes(&self) -> u64 {
+        self.total_bytes
+    }
+
+    pub fn transactions(&self) -> impl Iterator<Item = &PendingTx> {
+        self.txs.values()
+    }
+
+    pub fn account_count(&self) -> usize {
+        self.accounts.len()
+    }
+
+    pub fn len(&self) -> usize {
+        self.txs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.txs.is_empty()
+    }
+}
@@ -0,0 +1,196 @@
+use std::collections::{BTreeMap, HashMap};
+use std::iter::Peekable;
+
+use crate::{AccountId, Mempool, MempoolError, MempoolFilter, PendingTx, TxId};
+
+// 한 계정이 풀을 독차지하지 못하게 막는 한도들입니다.
+// Quotas that stop a single account from flooding the pool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AdmissionLimits {
+    // 계정 하나가 풀에 둘 수 있는 최대 트랜잭션 수
+    pub max_txs_per_account: usize,
+    // 계정 하나가 차지할 수 있는 최대 payload 바이트
+    pub max_bytes_per_account: u64,
+    // 풀 전체가 차지할 수 있는 최대 payload 바이트
+    pub max_total_bytes: u64,
+}
+
+// 입장 심사 결과입니다. bool 대신 이유까지 담아 돌려주므로 호출하는 쪽이 분기할 수 있습니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AdmissionDecision {
+    Accepted,
+    // 전역 바이트 예산을 맞추기 위해 evicted의 트랜잭션들을 내보내고 수락합니다(축출 순서대로).
+    AcceptedWithEvictions { evicted: Vec<TxId> },
+    Rejected(AdmissionRejection),
+}
+
+impl AdmissionDecision {
+    pub fn is_accepted(&self) -> bool {
+        !matches!(self, AdmissionDecision::Rejected(_))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum AdmissionRejection {
+    #[error("account {account} already holds {limit} transactions")]
+    AccountTxLimit { account: AccountId, limit: usize },
+    #[error("account {account} would hold {requested} bytes, limit is {limit}")]
+    AccountByteLimit {
+        account: AccountId,
+        requested: u64,
+        limit: u64,
+    },
+    #[error("payload of {size} bytes exceeds the pool budget of {budget} bytes")]
+    PayloadTooLarge { size: u64, budget: u64 },
+    // 예산이 가득 찼고, 이 트랜잭션이 가장 많이 쓰는 계정 안에서 nonce가 가장 커서 스스로가 축출 대상인 경우
+    #[error(
+        "pool budget is exhausted and the transaction has the highest nonce of the heaviest account"
+    )]
+    BudgetExhausted,
+    #[error(transparent)]
+    Mempool(#[from] MempoolError),
+}
+
+// 계정별 한도와 전역 바이트 예산을 적용하는 입장 심사기입니다.
+// 상태는 Mempool이 들고 있고, 심사기는 규칙만 가집니다.
+pub struct AdmissionController {
+    limits: AdmissionLimits,
+}
+
+impl AdmissionController {
+    pub fn new(limits: AdmissionLimits) -> Self {
+        Self { limits }
+    }
+
+    pub fn limits(&self) -> &AdmissionLimits {
+        &self.limits
+    }
+
+    // 풀을 바꾸지 않고 tx가 들어오면 어떻게 될지 판단합니다.
+    // 검사 순서: 1) 풀 자체 규칙(id/nonce) 2) 단일 payload 크기 3) 계정 트랜잭션 수 4) 계정 바이트 5) 전역 바이트 예산
+    pub fn check(&self, pool: &Mempool, tx: &PendingTx) -> AdmissionDecision {
+        match self.plan(pool, tx) {
+            Ok(evicted) if evicted.is_empty() => AdmissionDecision::Accepted,
+            Ok(evicted) => AdmissionDecision::AcceptedWithEvictions { evicted },
+            Err(rejection) => AdmissionDecision::Rejected(rejection),
+        }
+    }
+
+    // check와 같은 판단을 내린 뒤, 수락이면 축출을 실제로 적용하고 tx를 풀에 넣습니다.
+    pub fn admit(&self, pool: &mut Mempool, tx: PendingTx) -> AdmissionDecision {
+        let decision = self.check(pool, &tx);
+        if let AdmissionDecision::AcceptedWithEvictions { evicted } = &decision {
+            for id in evicted {
+                pool.remove(id);
+            }
+        }
+        if decision.is_accepted() {
+            pool.insert(tx)
+                .expect("check_insert already validated the transaction");
+        }
+        decision
+    }
+
+    // filter_transactions처럼 슬라이스를 받아, 각 트랜잭션에 대한 판단을 참조와 함께 돌려줍니다.
+    // 모든 판단은 현재 풀 상태 기준이며 서로의 영향을 반영하지 않는 dry-run입니다.
+    pub fn check_all<'a>(
+        &self,
+        pool: &Mempool,
+        txs: &'a [PendingTx],
+    ) -> Vec<(&'a PendingTx, AdmissionDecision)> {
+        txs.iter().map(|tx| (tx, self.check(pool, tx))).collect()
+    }
+
+    // filter_transactions에 그대로 넘길 수 있는 필터로 감쌉니다.
+    pub fn filter<'a>(&'a self, pool: &'a Mempool) -> AdmissionFilter<'a> {
+        AdmissionFilter {
+            controller: self,
+            pool,
+        }
+    }
+
+    fn plan(&self, pool: &Mempool, tx: &PendingTx) -> Result<Vec<TxId>, AdmissionRejection> {
+        pool.check_insert(tx)?;
+
+        let limits = &self.limits;
+        let size = tx.payload_size as u64;
+        if size > limits.max_total_bytes {
+            return Err(AdmissionRejection::PayloadTooLarge {
+                size,
+                budget: limits.max_total_bytes,
+            });
+        }
+
+        let index = pool.index();
+        let (count, bytes) = index
+            .account_stats(&tx.account)
+            .map_or((0, 0), |stats| (stats.tx_count(), stats.total_bytes));
+        if count + 1 > limits.max_txs_per_account {
+            return Err(AdmissionRejection::AccountTxLimit {
+                account: tx.account,
+                limit: limits.max_txs_per_account,
+            });
+        }
+        if bytes + size > limits.max_bytes_per_account {
+            return Err(AdmissionRejection::AccountByteLimit {
+                account: tx.account,
+                requested: bytes + size,
+                limit: limits.max_bytes_per_account,
+            });
+        }
+
+        let mut total = index.total_bytes() + size;
+        if total <= limits.max_total_bytes {
+            return Ok(Vec::new());
+        }
+
+        // 예산 초과: 가장 많은 바이트를 쓰는 계정에서 nonce가 가장 큰 트랜잭션부터 축출 계획을 세웁니다.
+        // 중간 nonce를 빼면 그 뒤 트랜잭션이 모두 future로 밀려나므로, 수수료와 관계없이 맨 뒤부터 뺍니다.
+        // 새 트랜잭션도 자기 계정의 후보로 함께 비교하며, nonce가 같으면 기존 트랜잭션을 남깁니다.
+        let mut usage: BTreeMap<&AccountId, u64> = index
+            .stats()
+            .map(|(account, stats)| (account, stats.total_bytes))
+            .collect();
+        *usage.entry(&tx.account).or_default() += size;
+        let mut cursors: HashMap<&AccountId, Peekable<_>> = HashMap::new();
+        let mut evicted = Vec::new();
+
+        while total > limits.max_total_bytes {
+            // 사용량이 같으면 계정 id가 앞선 쪽을 골라 결과가 항상 같게 합니다.
+            let (&heaviest, _) = usage
+                .iter()
+                .max_by(|(a_name, a), (b_name, b)| a.cmp(b).then_with(|| b_name.cmp(a_name)))
+                .expect("usage includes the incoming transaction's account");
+            let cursor = cursors
+                .entry(heaviest)
+                .or_insert_with(|| index.highest_nonce_first(heaviest).peekable());
+
+            let incoming_is_last = *heaviest == tx.account
+                && cursor.peek().is_none_or(|victim| tx.nonce >= victim.nonce);
+            if incoming_is_last {
+                return Err(AdmissionRejection::BudgetExhausted);
+            }
+
+            let victim = cursor
+                .next()
+                .expect("an account with usage left still has transactions");
+            let victim_size = victim.payload_size as u64;
+            total -= victim_size;
+            *usage.get_mut(heaviest).expect("heaviest came from usage") -= victim_size;
+            evicted.push(victim.id);
+        }
+        Ok(evicted)
+    }
+}
+
+// filter_transactions에 넘기기 위한 어댑터입니다. 거절(Rejected)이 아니면 통과시킵니다.
+pub struct AdmissionFilter<'a> {
+    controller: &'a AdmissionController,
+    pool: &'a Mempool,
+}
+
+impl MempoolFilter for AdmissionFilter<'_> {
+    fn allow(&self, tx: &PendingTx) -> bool {
+        self.controller.check(self.pool, tx).is_accepted()
+    }
+}
@@ -0,0 +1,127 @@
+use std::fmt;
+use std::str::FromStr;
+
+// 32바이트 식별자를 문자열로 읽을 때 생길 수 있는 오류입니다.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum IdParseError {
+    #[error("invalid hex string: {0}")]
+    InvalidHex(#[from] hex::FromHexError),
+    #[error("invalid base58 string: {0}")]
+    InvalidBase58(#[from] bs58::decode::Error),
+    #[error("expected 32 bytes, decoded {got}")]
+    InvalidLength { got: usize },
+    // 16진수 표기는 글자 수로 알려 줍니다. 홀수 길이는 바이트 수로 나타낼 수 없기 때문입니다.
+    #[error("expected 64 hex characters, got {got}")]
+    InvalidHexLength { got: usize },
+}
+
+// hex 표기(64글자)인지, 아니면 base58 표기인지 판단해 32바이트로 되돌립니다.
+// base58로 인코딩한 32바이트는 최대 44글자라, 64글자짜리 16진수 문자열과 겹치지 않습니다.
+fn decode_32(s: &str) -> Result<[u8; 32], IdParseError> {
+    if s.len() == 64 && s.bytes().all(|b| b.is_ascii_hexdigit()) {
+        decode_hex_32(s)
+    } else {
+        decode_base58_32(s)
+    }
+}
+
+fn decode_hex_32(s: &str) -> Result<[u8; 32], IdParseError> {
+    let mut bytes = [0u8; 32];
+    if s.len() != 64 {
+        return Err(IdParseError::InvalidHexLength { got: s.len() });
+    }
+    hex::decode_to_slice(s, &mut bytes)?;
+    Ok(bytes)
+}
+
+fn decode_base58_32(s: &str) -> Result<[u8; 32], IdParseError> {
+    let decoded = bs58::decode(s).into_vec()?;
+    decoded
+        .try_into()
+        .map_err(|decoded: Vec<u8>| IdParseError::InvalidLength { got: decoded.len() })
+}
+
+// TxId와 AccountId는 모양(32바이트)도, 할 수 있는 일도 같지만 서로 섞어 쓰면 안 되므로
+// 같은 구현을 두 개의 다른 타입으로 찍어 냅니다.
+macro_rules! define_id {
+    ($name:ident) => {
+        #[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub struct $name([u8; 32]);
+
+        impl $name {
+            pub const fn new(bytes: [u8; 32]) -> Self {
+                Self(bytes)
+            }
+
+            pub fn as_bytes(&self) -> &[u8; 32] {
+                &self.0
+            }
+
+            pub fn from_hex(s: &str) -> Result<Self, IdParseError> {
+                decode_hex_32(s).map(Self)
+            }
+
+            pub fn from_base58(s: &str) -> Result<Self, IdParseError> {
+                decode_base58_32(s).map(Self)
+            }
+
+            pub fn to_hex(&self) -> String {
+                hex::encode(self.0)
+            }
+
+            pub fn to_base58(&self) -> String {
+                bs58::encode(self.0).into_string()
+            }
+        }
+
+        impl From<[u8; 32]> for $name {
+            fn from(bytes: [u8; 32]) -> Self {
+                Self(bytes)
+            }
+        }
+
+        // 문자열은 base58과 hex 표기를 모두 받습니다.
+        impl FromStr for $name {
+            type Err = IdParseError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                decode_32(s).map(Self)
+            }
+        }
+
+        // 출력은 Solana 탐색기처럼 base58을 기본으로 씁니다.
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.to_base58())
+            }
+        }
+
+        // JSON 등에서는 Display와 같은 base58 문자열로 읽고 씁니다(hex도 읽을 수 있음).
+        impl serde::Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(self)
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let s = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+                s.parse().map_err(serde::de::Error::custom)
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}({})", stringify!($name), self.to_base58())
+            }
+        }
+    };
+}
+
+// 트랜잭션 해시(32바이트)입니다. Copy라서 맵 키로 써도 힙 문자열을 복제하지 않습니다.
+// A 32-byte transaction hash.
+define_id!(TxId);
+
+// 계정 공개키(32바이트)입니다.
+// A 32-byte account public key.
+define_id!(AccountId);
@@ -1,5 +1,19 @@
 use std::collections::BTreeMap;
 
+mod account_index;
+mod admission;
+mod ids;
+mod mempool;
+mod ranking;
+
+pub use account_index::AccountIndex;
+pub use admission::{
+    AdmissionController, AdmissionDecision, AdmissionFilter, AdmissionLimits, AdmissionRejection,
+};
+pub use ids::{AccountId, IdParseError, TxId};
+pub use mempool::{Mempool, MempoolError, QueuePlacement};
+pub use ranking::{StatsMetric, top_accounts};
+
 // 2. **도메인 모델 정의하기 (`src/lib.rs`)**
 //    - `PendingTx` 구조체를 선언하고 아래 필드를 추가합니다.
 //      - `pub id: String` — 트랜잭션 해시를 문자열로 표현합니다.
@@ -11,7 +25,7 @@ use std::collections::BTreeMap;
 //    - 각 구조체/열거형 위에는 "이 데이터가 왜 필요한지" 어린이도 이해할 수 있게 한국어+영어로 짧은 주석을 작성하세요.
 
 // 트랜잭션이 진행중인지, 시뮬레이션 결과가 나왔는지, 거절되었는지 상태를 나타내기 위한 열거형
-#[derive(Clone)]
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
 pub enum TxStatus {
     Pending,
     Simulated { compute_units: u64 },
@@ -19,13 +33,21 @@ pub enum TxStatus {
 }
 
 // 트랜잭션 정보를 담기 위한 구조체
-#[derive(Clone)]
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
 pub struct PendingTx {
-    pub id: String,
-    pub account: String,
+    // 32바이트 트랜잭션 해시. 문자열은 TxId::from_str로 검증한 뒤에만 들어옵니다.
+    pub id: TxId,
+    // 32바이트 계정 공개키
+    pub account: AccountId,
     pub fee_micro_lamports: u64,
     pub payload_size: u32,
     pub status: TxStatus,
+    // 노드가 트랜잭션을 처음 받은 시각(ms). 가장 오래 기다린 Pending 트랜잭션을 찾을 때 씁니다.
+    // When the node first received the transaction, in milliseconds.
+    pub received_at_ms: u64,
+    // 계정별 순번. 같은 계정의 트랜잭션은 nonce 순서대로만 실행될 수 있습니다.
+    // Per-account sequence number; an account's transactions execute strictly in nonce order.
+    pub nonce: u64,
 }
 
 // 3. **필터 추상화 설계 (`src/lib.rs`)**
@@ -58,11 +80,9 @@ impl MempoolFilter for ThresholdFilter {
             return false;
         }
         // 3단계: 시뮬레이션 실패 조건 확인
-        if self.reject_simulation_failures {
-            if let TxStatus::Rejected { .. } = tx.status {
-                // log::info!("시뮬레이션 실패 조건 불만족: {}", tx.id);
-                return false;
-            }
+        if self.reject_simulation_failures && matches!(tx.status, TxStatus::Rejected { .. }) {
+            // log::info!("시뮬레이션 실패 조건 불만족: {}", tx.id);
+            return false;
         }
         true
     }
@@ -94,48 +114,122 @@ pub fn filter_transactions<'a, F: MempoolFilter>(
 // 트랜잭션들을 계정별로 그룹화하여 BTreeMap으로 반환하는 함수
 // 계정 주소를 키로 사용하여 사전식 정렬이 보장되며,
 // 각 계정의 트랜잭션들을 별도 벡터로 관리하여 후속 통계 계산에 활용
-pub fn group_by_account(txs: &[PendingTx]) -> BTreeMap<String, Vec<PendingTx>> {
+// 각 벡터는 nonce 오름차순(같은 nonce는 입력 순서)으로 정렬되어 실행 순서대로 읽을 수 있습니다.
+pub fn group_by_account(txs: &[PendingTx]) -> BTreeMap<AccountId, Vec<PendingTx>> {
     let mut grouped = BTreeMap::new();
     for tx in txs {
         grouped
-            .entry(tx.account.clone())
+            .entry(tx.account)
             .or_insert(Vec::new())
             .push((*tx).clone());
     }
+    for account_txs in grouped.values_mut() {
+        account_txs.sort_by_key(|tx: &PendingTx| tx.nonce);
+    }
     grouped
 }
 
+// 계정 하나의 멤풀 사용량 요약입니다.
+// 합계 필드는 입력 타입보다 넓은 타입을 써서, 트랜잭션이 많은 계정에서도 overflow panic이 나지 않습니다.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct AccountStats {
-    pub total_fee: u64,
-    pub total_bytes: u32,
+    // u64 수수료의 합이라 u128로 넓혀 둡니다.
+    pub total_fee: u128,
+    // u32 페이로드 크기의 합이라 u64로 넓혀 둡니다.
+    pub total_bytes: u64,
     pub pending: usize,
+    pub simulated: usize,
+    pub rejected: usize,
+    // Simulated 상태 트랜잭션의 compute unit 합계
+    pub total_compute_units: u128,
+    pub min_fee: Option<u64>,
+    pub max_fee: Option<u64>,
+    // 가장 오래 기다린 Pending 트랜잭션의 수신 시각(ms). Pending이 없으면 None입니다.
+    pub oldest_pending_at_ms: Option<u64>,
+}
+
+impl AccountStats {
+    // 상태와 상관없이 이 계정에 속한 트랜잭션 수
+    pub fn tx_count(&self) -> usize {
+        self.pending + self.simulated + self.rejected
+    }
+
+    // 트랜잭션당 평균 수수료(소수점 이하 버림). 트랜잭션이 없으면 None입니다.
+    pub fn mean_fee(&self) -> Option<u64> {
+        let count = self.tx_count() as u128;
+        if count == 0 {
+            return None;
+        }
+        // 평균은 최댓값을 넘을 수 없으므로 u64로 되돌려도 안전합니다.
+        Some((self.total_fee / count) as u64)
+    }
+
+    // now_ms 기준으로 가장 오래된 Pending 트랜잭션이 기다린 시간(ms)
+    pub fn oldest_pending_age_ms(&self, now_ms: u64) -> Option<u64> {
+        self.oldest_pending_at_ms
+            .map(|received_at| now_ms.saturating_sub(received_at))
+    }
+
+    // 트랜잭션 하나가 계정에 더해질 때의 기여분을 누적합니다.
+    // 전체 재계산(compute_account_stats)과 증분 갱신(AccountIndex)이 같은 규칙을 쓰도록 한 곳에 모아 둡니다.
+    pub(crate) fn record(&mut self, tx: &PendingTx) {
+        self.total_fee += tx.fee_micro_lamports as u128;
+        self.total_bytes += tx.payload_size as u64;
+        self.min_fee = Some(
+            self.min_fee
+                .map_or(tx.fee_micro_lamports, |fee| fee.min(tx.fee_micro_lamports)),
+        );
+        self.max_fee = Some(
+            self.max_fee
+                .map_or(tx.fee_micro_lamports, |fee| fee.max(tx.fee_micro_lamports)),
+        );
+        match tx.status {
+            TxStatus::Pending => {
+                self.pending += 1;
+                self.oldest_pending_at_ms = Some(
+                    self.oldest_pending_at_ms
+                        .map_or(tx.received_at_ms, |at| at.min(tx.received_at_ms)),
+                );
+            }
+            TxStatus::Simulated { compute_units } => {
+                self.simulated += 1;
+                self.total_compute_units += compute_units as u128;
+            }
+            TxStatus::Rejected { .. } => self.rejected += 1,
+        }
+    }
+
+    // record의 역연산: 트랜잭션이 빠지거나 상태가 바뀌기 직전에 이전 기여분을 되돌립니다.
+    // 최솟값/최댓값/가장 오래된 시각은 빼기로 되돌릴 수 없으므로 그대로 두고,
+    // 호출하는 쪽(AccountIndex)이 남은 트랜잭션 기준으로 다시 채워야 합니다.
+    pub(crate) fn forget(&mut self, tx: &PendingTx) {
+        self.total_fee -= tx.fee_micro_lamports as u128;
+        self.total_bytes -= tx.payload_size as u64;
+        match tx.status {
+            TxStatus::Pending => self.pending -= 1,
+            TxStatus::Simulated { compute_units } => {
+                self.simulated -= 1;
+                self.total_compute_units -= compute_units as u128;
+            }
+            TxStatus::Rejected { .. } => self.rejected -= 1,
+        }
+    }
 }
 
 // 계정별 트랜잭션 그룹을 분석하여 통계 정보를 계산하는 함수
 // 각 계정의 총 수수료, 총 바이트 크기, Pending 상태 개수를 집계하여
 // AccountStats 구조체로 반환하며, BTreeMap을 통해 사전식 정렬 보장
+// 매번 전체를 순회하므로, 자주 갱신되는 대시보드에서는 AccountIndex의 증분 통계를 사용하세요.
 pub fn compute_account_stats(
-    grouped: &BTreeMap<String, Vec<PendingTx>>,
-) -> BTreeMap<String, AccountStats> {
+    grouped: &BTreeMap<AccountId, Vec<PendingTx>>,
+) -> BTreeMap<AccountId, AccountStats> {
     let mut stats = BTreeMap::new();
     for (account, txs) in grouped {
-        let total_fee = txs.iter().map(|tx| tx.fee_micro_lamports).sum();
-        let total_bytes = txs.iter().map(|tx| tx.payload_size).sum();
-        let pending = txs
-            .iter()
-            .filter(|tx| match tx.status {
-                TxStatus::Pending => true,
-                _ => false,
-            })
-            .count();
-        stats.insert(
-            account.clone(),
-            AccountStats {
-                total_fee,
-                total_bytes,
-                pending,
-            },
-        );
+        let mut account_stats = AccountStats::default();
+        for tx in txs {
+            account_stats.record(tx);
+        }
+        stats.insert(*account, account_stats);
     }
     stats
 }
@@ -0,0 +1,235 @@
+use std::collections::BTreeMap;
+use std::ops::Range;
+
+use crate::{AccountId, AccountIndex, PendingTx, TxId, TxStatus};
+
+// 트랜잭션이 들어간 대기열 종류입니다.
+// Ready: 바로 실행 가능(기대 nonce부터 빈틈 없이 이어짐), Future: 앞 nonce가 비어 있어 기다려야 함.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueuePlacement {
+    Ready,
+    Future,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum MempoolError {
+    #[error("transaction {id} is already in the pool")]
+    DuplicateId { id: TxId },
+    #[error("account {account} expects nonce {expected} or higher, got {got}")]
+    NonceTooLow {
+        account: AccountId,
+        expected: u64,
+        got: u64,
+    },
+    #[error("account {account} already has a transaction with nonce {nonce}")]
+    DuplicateNonce { account: AccountId, nonce: u64 },
+    // u64::MAX 다음 nonce는 없으므로 그 트랜잭션 뒤로는 대기열을 이어 갈 수 없습니다.
+    #[error("account {account} used nonce {nonce}, which has no successor")]
+    NonceOutOfRange { account: AccountId, nonce: u64 },
+}
+
+// 계정 하나의 nonce 대기열입니다. 값은 트랜잭션 id이고, 원본은 AccountIndex가 소유합니다.
+// 불변식: ready는 next_nonce부터 빈틈 없이 이어지고, future의 첫 nonce 앞에는 항상 빈틈이 있습니다.
+#[derive(Default)]
+struct AccountQueue {
+    // 체인 상태 기준으로 다음에 실행될 nonce
+    next_nonce: u64,
+    ready: BTreeMap<u64, TxId>,
+    future: BTreeMap<u64, TxId>,
+}
+
+impl AccountQueue {
+    // ready 대기열 바로 다음 nonce. 이 값이 들어오면 ready 뒤에 이어 붙일 수 있습니다.
+    fn ready_end(&self) -> u64 {
+        self.next_nonce + self.ready.len() as u64
+    }
+
+    fn contains(&self, nonce: u64) -> bool {
+        self.ready.contains_key(&nonce) || self.future.contains_key(&nonce)
+    }
+
+    // 빈틈이 메워졌다면 future 앞쪽부터 이어지는 트랜잭션을 ready로 옮깁니다.
+    fn promote(&mut self) {
+        let mut expected = self.ready_end();
+        while let Some(id) = self.future.remove(&expected) {
+            self.ready.insert(expected, id);
+            expected += 1;
+        }
+    }
+
+    // nonce가 빠지거나 next_nonce가 바뀐 뒤 ready/future를 처음부터 다시 나눕니다.
+    fn rebalance(&mut self) {
+        self.future.append(&mut self.ready);
+        self.promote();
+    }
+
+    // ready 끝부터 future 사이의 비어 있는 nonce 구간들
+    fn gaps(&self) -> Vec<Range<u64>> {
+        let mut gaps = Vec::new();
+        let mut cursor = self.ready_end();
+        for &nonce in self.future.keys() {
+            if nonce > cursor {
+                gaps.push(cursor..nonce);
+            }
+            cursor = nonce + 1;
+        }
+        gaps
+    }
+}
+
+// nonce 순서를 아는 멤풀입니다. 계정마다 실행 가능한 ready 대기열과, 앞 nonce를 기다리는 future 대기열을 나눠 둡니다.
+// A mempool that keeps each account's transactions split into an executable ready queue and a gapped future queue.
+#[derive(Default)]
+pub struct Mempool {
+    // 트랜잭션 원본과 계정 통계는 AccountIndex가 관리합니다.
+    index: AccountIndex,
+    // 계정 → nonce 대기열. 트랜잭션이 모두 빠져도 체인 nonce를 기억하기 위해 남겨 둡니다.
+    queues: BTreeMap<AccountId, AccountQueue>,
+}
+
+impl Mempool {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // 체인 상태에서 읽은 계정의 다음 nonce를 알려 줍니다.
+    // 이보다 낮은 nonce의 트랜잭션은 이미 실행된 것이므로 풀에서 빠지고, 빠진 트랜잭션을 돌려줍니다.
+    // 값이 낮아지는 경우(reorg 등)에는 ready였던 트랜잭션이 빈틈 뒤의 future로 내려갑니다.
+    pub fn set_account_nonce(&mut self, account: &AccountId, next_nonce: u64) -> Vec<PendingTx> {
+        let queue = self.queues.entry(*account).or_default();
+        queue.next_nonce = next_nonce;
+        queue.future.append(&mut queue.ready);
+        let keep = queue.future.split_off(&next_nonce);
+        let stale = std::mem::replace(&mut queue.future, keep);
+        queue.promote();
+
+        stale
+            .into_values()
+            .filter_map(|id| self.index.remove(&id))
+            .collect()
+    }
+
+    // insert가 성공할지 풀을 바꾸지 않고 미리 확인합니다. 입장 심사(AdmissionController)의 dry-run에 씁니다.
+    pub fn check_insert(&self, tx: &PendingTx) -> Result<(), MempoolError> {
+        if self.index.get(&tx.id).is_some() {
+            return Err(MempoolError::DuplicateId { id: tx.id });
+        }
+        if tx.nonce == u64::MAX {
+            return Err(MempoolError::NonceOutOfRange {
+                account: tx.account,
+                nonce: tx.nonce,
+            });
+        }
+        let Some(queue) = self.queues.get(&tx.account) else {
+            return Ok(());
+        };
+        if tx.nonce < queue.next_nonce {
+            return Err(MempoolError::NonceTooLow {
+                account: tx.account,
+                expected: queue.next_nonce,
+                got: tx.nonce,
+            });
+        }
+        if queue.contains(tx.nonce) {
+            return Err(MempoolError::DuplicateNonce {
+                account: tx.account,
+                nonce: tx.nonce,
+            });
+        }
+        Ok(())
+    }
+
+    // 트랜잭션을 nonce에 맞는 대기열에 넣습니다.
+    // 빈틈을 메우는 트랜잭션이 들어오면 뒤에 기다리던 future 트랜잭션들이 ready로 승격됩니다.
+    pub fn insert(&mut self, tx: PendingTx) -> Result<QueuePlacement, MempoolError> {
+        self.check_insert(&tx)?;
+        let queue = self.queues.entry(tx.account).or_default();
+        let placement = if tx.nonce == queue.ready_end() {
+            queue.ready.insert(tx.nonce, tx.id);
+            queue.promote();
+            QueuePlacement::Ready
+        } else {
+            queue.future.insert(tx.nonce, tx.id);
+            QueuePlacement::Future
+        };
+        self.index.insert(tx);
+        Ok(placement)
+    }
+
+    // 트랜잭션을 풀에서 뺍니다. ready 중간에서 빠지면 그 뒤 트랜잭션들은 다시 future로 내려갑니다.
+    pub fn remove(&mut self, id: &TxId) -> Option<PendingTx> {
+        let tx = self.index.remove(id)?;
+        if let Some(queue) = self.queues.get_mut(&tx.account) {
+            queue.ready.remove(&tx.nonce);
+            queue.future.remove(&tx.nonce);
+            queue.rebalance();
+        }
+        Some(tx)
+    }
+
+    pub fn update_status(&mut self, id: &TxId, status: TxStatus) -> Option<TxStatus> {
+        self.index.update_status(id, status)
+    }
+
+    pub fn get(&self, id: &TxId) -> Option<&PendingTx> {
+        self.index.get(id)
+    }
+
+    // 계정 통계(AccountStats)는 인덱스가 증분으로 유지하므로 그대로 빌려줍니다.
+    pub fn index(&self) -> &AccountIndex {
+        &self.index
+    }
+
+    pub fn next_nonce(&self, account: &AccountId) -> Option<u64> {
+        self.queues.get(account).map(|queue| queue.next_nonce)
+    }
+
+    // 지금 바로 실행할 수 있는 트랜잭션을 nonce 순서대로 돌려줍니다.
+    pub fn ready(&self, account: &AccountId) -> Vec<&PendingTx> {
+        self.queued(account, |queue| &queue.ready)
+    }
+
+    // 앞 nonce가 비어 있어 기다리는 트랜잭션을 nonce 순서대로 돌려줍니다.
+    pub fn future(&self, account: &AccountId) -> Vec<&PendingTx> {
+        self.queued(account, |queue| &queue.future)
+    }
+
+    // 계정의 비어 있는 nonce 구간들(start..end, end 미포함)
+    pub fn gaps(&self, account: &AccountId) -> Vec<Range<u64>> {
+        self.queues
+            .get(account)
+            .map(AccountQueue::gaps)
+            .unwrap_or_default()
+    }
+
+    // 빈틈이 있는 계정만 모아 계정 id 순으로 돌려줍니다.
+    pub fn gaps_by_account(&self) -> BTreeMap<&AccountId, Vec<Range<u64>>> {
+        self.queues
+            .iter()
+            .map(|(account, queue)| (account, queue.gaps()))
+            .filter(|(_, gaps)| !gaps.is_empty())
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.index.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.index.is_empty()
+    }
+
+    fn queued(
+        &self,
+        account: &AccountId,
+        pick: impl Fn(&AccountQueue) -> &BTreeMap<u64, TxId>,
+    ) -> Vec<&PendingTx> {
+        let Some(queue) = self.queues.get(account) else {
+            return Vec::new();
+        };
+        pick(queue)
+            .values()
+            .filter_map(|id| self.index.get(id))
+            .collect()
+    }
+}
@@ -0,0 +1,68 @@
+use std::collections::BTreeMap;
+
+use crate::{AccountId, AccountStats};
+
+// 상위 N개 계정 리포트를 어떤 기준으로 정렬할지 고르는 열거형입니다.
+// Which AccountStats value the top-N report is sorted by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatsMetric {
+    TotalFee,
+    MeanFee,
+    MinFee,
+    MaxFee,
+    TotalBytes,
+    TxCount,
+    Pending,
+    Simulated,
+    Rejected,
+    ComputeUnits,
+    // 나이는 기준 시각이 있어야 계산할 수 있으므로 now_ms를 함께 받습니다.
+    OldestPendingAge { now_ms: u64 },
+}
+
+impl AccountStats {
+    // 지표 값을 u128 하나로 통일해 돌려줍니다. 값이 없는 지표(예: Pending이 없는 계정의 나이)는 0입니다.
+    pub fn metric(&self, metric: StatsMetric) -> u128 {
+        match metric {
+            StatsMetric::TotalFee => self.total_fee,
+            StatsMetric::MeanFee => self.mean_fee().unwrap_or(0) as u128,
+            StatsMetric::MinFee => self.min_fee.unwrap_or(0) as u128,
+            StatsMetric::MaxFee => self.max_fee.unwrap_or(0) as u128,
+            StatsMetric::TotalBytes => self.total_bytes as u128,
+            StatsMetric::TxCount => self.tx_count() as u128,
+            StatsMetric::Pending => self.pending as u128,
+            StatsMetric::Simulated => self.simulated as u128,
+            StatsMetric::Rejected => self.rejected as u128,
+            StatsMetric::ComputeUnits => self.total_compute_units,
+            StatsMetric::OldestPendingAge { now_ms } => {
+                self.oldest_pending_age_ms(now_ms).unwrap_or(0) as u128
+            }
+        }
+    }
+}
+
+// 지표 값이 큰 계정부터 최대 n개를 돌려줍니다. 값이 같으면 계정 id 바이트 순으로 정렬해 결과가 항상 같습니다.
+// compute_account_stats 결과와 AccountIndex가 같은 정렬 규칙을 공유하도록 여기 한 곳에 둡니다.
+pub(crate) fn rank<'a>(
+    stats: impl Iterator<Item = (&'a AccountId, &'a AccountStats)>,
+    metric: StatsMetric,
+    n: usize,
+) -> Vec<(&'a AccountId, &'a AccountStats)> {
+    let mut ranked: Vec<(&AccountId, &AccountStats)> = stats.collect();
+    ranked.sort_by(|(a_name, a), (b_name, b)| {
+        b.metric(metric)
+            .cmp(&a.metric(metric))
+            .then_with(|| a_name.cmp(b_name))
+    });
+    ranked.truncate(n);
+    ranked
+}
+
+// compute_account_stats가 만든 맵에서 상위 n개 계정을 뽑는 리포트 함수
+pub fn top_accounts(
+    stats: &BTreeMap<AccountId, AccountStats>,
+    metric: StatsMetric,
+    n: usize,
+) -> Vec<(&AccountId, &AccountStats)> {
+    rank(stats.iter(), metric, n)
+}
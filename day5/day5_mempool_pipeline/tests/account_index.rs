@@ -0,0 +1,221 @@
+// AccountIndex가 삽입/삭제/상태 변경마다 계정 통계를 증분으로 갱신하고,
+// 그 결과가 group_by_account + compute_account_stats 전체 재계산과 항상 같은지 검증합니다.
+
+mod common;
+
+use common::{account_id, label, tx_id};
+use day5_mempool_pipeline::*;
+
+fn tx(id: &str, account: &str, fee: u64, size: u32, status: TxStatus) -> PendingTx {
+    PendingTx {
+        id: tx_id(id),
+        account: account_id(account),
+        fee_micro_lamports: fee,
+        payload_size: size,
+        status,
+        received_at_ms: 0,
+        nonce: 0,
+    }
+}
+
+// 인덱스가 들고 있는 트랜잭션으로 전체 재계산한 결과
+fn recompute(index: &AccountIndex) -> std::collections::BTreeMap<AccountId, AccountStats> {
+    let txs: Vec<PendingTx> = index.transactions().cloned().collect();
+    compute_account_stats(&group_by_account(&txs))
+}
+
+#[test]
+fn insert_accumulates_stats_per_account() {
+    let mut index = AccountIndex::new();
+    index.insert(tx("tx1", "alice", 1000, 500, TxStatus::Pending));
+    index.insert(tx(
+        "tx2",
+        "alice",
+        2000,
+        300,
+        TxStatus::Simulated { compute_units: 100 },
+    ));
+    index.insert(tx("tx3", "bob", 500, 800, TxStatus::Pending));
+
+    let alice = index.account_stats(&account_id("alice")).unwrap();
+    assert_eq!(alice.total_fee, 3000);
+    assert_eq!(alice.total_bytes, 800);
+    assert_eq!(alice.pending, 1);
+
+    assert_eq!(index.len(), 3);
+    assert_eq!(index.account_count(), 2);
+    assert_eq!(index.snapshot(), recompute(&index));
+}
+
+#[test]
+fn remove_subtracts_and_drops_empty_accounts() {
+    let mut index = AccountIndex::new();
+    index.insert(tx("tx1", "alice", 1000, 500, TxStatus::Pending));
+    index.insert(tx("tx2", "alice", 2000, 300, TxStatus::Pending));
+    index.insert(tx("tx3", "bob", 500, 800, TxStatus::Pending));
+
+    let removed = index.remove(&tx_id("tx1")).expect("tx1 must be indexed");
+    assert_eq!(removed.id, tx_id("tx1"));
+    assert_eq!(
+        index.account_stats(&account_id("alice")).unwrap().total_fee,
+        2000
+    );
+    assert_eq!(
+        index.account_stats(&account_id("alice")).unwrap().pending,
+        1
+    );
+
+    // bob의 유일한 트랜잭션이 빠지면 계정 자체가 사라짐
+    index.remove(&tx_id("tx3"));
+    assert!(index.account_stats(&account_id("bob")).is_none());
+    assert_eq!(index.account_count(), 1);
+
+    // 없는 id 제거는 아무 일도 하지 않음
+    assert!(index.remove(&tx_id("missing")).is_none());
+    assert_eq!(index.snapshot(), recompute(&index));
+}
+
+#[test]
+fn update_status_moves_pending_count() {
+    let mut index = AccountIndex::new();
+    index.insert(tx("tx1", "alice", 1000, 500, TxStatus::Pending));
+    index.insert(tx("tx2", "alice", 2000, 300, TxStatus::Pending));
+
+    let previous = index.update_status(&tx_id("tx1"), TxStatus::Simulated { compute_units: 42 });
+    assert!(matches!(previous, Some(TxStatus::Pending)));
+    assert_eq!(
+        index.account_stats(&account_id("alice")).unwrap().pending,
+        1
+    );
+
+    let previous = index.update_status(
+        &tx_id("tx1"),
+        TxStatus::Rejected {
+            reason: "blockhash expired".to_string(),
+        },
+    );
+    assert!(matches!(
+        previous,
+        Some(TxStatus::Simulated { compute_units: 42 })
+    ));
+    assert_eq!(
+        index.account_stats(&account_id("alice")).unwrap().pending,
+        1
+    );
+
+    assert!(
+        index
+            .update_status(&tx_id("missing"), TxStatus::Pending)
+            .is_none()
+    );
+    assert_eq!(index.snapshot(), recompute(&index));
+}
+
+#[test]
+fn insert_with_same_id_replaces_previous_contribution() {
+    let mut index = AccountIndex::new();
+    index.insert(tx("tx1", "alice", 1000, 500, TxStatus::Pending));
+
+    // 같은 id가 다른 계정으로 다시 들어오면 alice 기여분은 빠지고 bob에 더해짐
+    let replaced = index.insert(tx("tx1", "bob", 3000, 100, TxStatus::Pending));
+    assert_eq!(replaced.unwrap().account, account_id("alice"));
+    assert!(index.account_stats(&account_id("alice")).is_none());
+    assert_eq!(
+        index.account_stats(&account_id("bob")).unwrap().total_fee,
+        3000
+    );
+    assert_eq!(index.len(), 1);
+}
+
+#[test]
+fn incremental_stats_match_full_recompute_over_many_changes() {
+    let mut index = AccountIndex::new();
+    let accounts = ["a", "b", "c", "d"];
+
+    for i in 0..400u64 {
+        let status = match i % 3 {
+            0 => TxStatus::Pending,
+            1 => TxStatus::Simulated { compute_units: i },
+            _ => TxStatus::Rejected {
+                reason: "error".to_string(),
+            },
+        };
+        let account = accounts[(i % 4) as usize];
+        index.insert(tx(
+            &format!("tx{i}"),
+            account,
+            i + 1,
+            (i % 97) as u32,
+            status,
+        ));
+    }
+    for i in (0..400u64).step_by(7) {
+        index.remove(&tx_id(&format!("tx{i}")));
+    }
+    for i in (1..400u64).step_by(5) {
+        index.update_status(&tx_id(&format!("tx{i}")), TxStatus::Pending);
+    }
+
+    assert_eq!(index.snapshot(), recompute(&index));
+    let listed: Vec<String> = index
+        .stats()
+        .map(|(account, _)| label(account.as_bytes()))
+        .collect();
+    assert_eq!(listed, vec!["a", "b", "c", "d"]);
+}
+
+#[test]
+fn extremes_are_restored_from_remaining_transactions() {
+    let mut index = AccountIndex::new();
+    index.insert(PendingTx {
+        received_at_ms: 10,
+        ..tx("cheap", "alice", 100, 10, TxStatus::Pending)
+    });
+    index.insert(PendingTx {
+        received_at_ms: 20,
+        ..tx("mid", "alice", 500, 10, TxStatus::Pending)
+    });
+    index.insert(PendingTx {
+        received_at_ms: 30,
+        ..tx("rich", "alice", 900, 10, TxStatus::Pending)
+    });
+
+    let alice = index.account_stats(&account_id("alice")).unwrap();
+    assert_eq!((alice.min_fee, alice.max_fee), (Some(100), Some(900)));
+    assert_eq!(alice.oldest_pending_at_ms, Some(10));
+
+    // 최솟값/최댓값을 가진 트랜잭션이 빠지면 남은 것 기준으로 다시 계산됨
+    index.remove(&tx_id("cheap"));
+    index.remove(&tx_id("rich"));
+    let alice = index.account_stats(&account_id("alice")).unwrap();
+    assert_eq!((alice.min_fee, alice.max_fee), (Some(500), Some(500)));
+    assert_eq!(alice.oldest_pending_at_ms, Some(20));
+
+    // Pending에서 벗어나면 가장 오래된 Pending 시각도 사라짐
+    index.update_status(&tx_id("mid"), TxStatus::Simulated { compute_units: 7 });
+    let alice = index.account_stats(&account_id("alice")).unwrap();
+    assert_eq!(alice.oldest_pending_at_ms, None);
+    assert_eq!(alice.total_compute_units, 7);
+    assert_eq!(index.snapshot(), recompute(&index));
+}
+
+#[test]
+fn top_accounts_on_index_matches_free_function() {
+    let mut index = AccountIndex::new();
+    index.insert(tx("a1", "alice", 100, 10, TxStatus::Pending));
+    index.insert(tx("b1", "bob", 700, 10, TxStatus::Pending));
+    index.insert(tx("c1", "carol", 300, 10, TxStatus::Pending));
+
+    let snapshot = index.snapshot();
+    let from_index: Vec<String> = index
+        .top_accounts(StatsMetric::TotalFee, 2)
+        .into_iter()
+        .map(|(account, _)| label(account.as_bytes()))
+        .collect();
+    let from_map: Vec<String> = top_accounts(&snapshot, StatsMetric::TotalFee, 2)
+        .into_iter()
+        .map(|(account, _)| label(account.as_bytes()))
+        .collect();
+    assert_eq!(from_index, vec!["bob", "carol"]);
+    assert_eq!(from_index, from_map);
+}
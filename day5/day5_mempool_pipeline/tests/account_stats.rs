@@ -0,0 +1,185 @@
+// 확장된 AccountStats가 상태별 개수, compute unit 합계, 수수료 최소/최대/평균,
+// 가장 오래된 Pending 나이를 정확히 계산하고, 큰 값에서도 overflow 없이 합산하는지 검증합니다.
+
+mod common;
+
+use common::{account_id, label, tx_id};
+use day5_mempool_pipeline::*;
+
+fn tx(id: &str, account: &str, fee: u64, size: u32, status: TxStatus, at: u64) -> PendingTx {
+    PendingTx {
+        id: tx_id(id),
+        account: account_id(account),
+        fee_micro_lamports: fee,
+        payload_size: size,
+        status,
+        received_at_ms: at,
+        nonce: 0,
+    }
+}
+
+fn fixture() -> Vec<PendingTx> {
+    vec![
+        tx("a1", "alice", 1000, 500, TxStatus::Pending, 1_000),
+        tx(
+            "a2",
+            "alice",
+            3000,
+            300,
+            TxStatus::Simulated { compute_units: 120 },
+            2_000,
+        ),
+        tx(
+            "a3",
+            "alice",
+            2000,
+            200,
+            TxStatus::Simulated { compute_units: 80 },
+            3_000,
+        ),
+        tx(
+            "a4",
+            "alice",
+            500,
+            100,
+            TxStatus::Rejected {
+                reason: "insufficient funds".to_string(),
+            },
+            4_000,
+        ),
+        tx("a5", "alice", 1500, 100, TxStatus::Pending, 500),
+        tx("b1", "bob", 9000, 1200, TxStatus::Pending, 5_000),
+        tx(
+            "c1",
+            "carol",
+            10,
+            50,
+            TxStatus::Simulated { compute_units: 999 },
+            6_000,
+        ),
+    ]
+}
+
+#[test]
+fn counts_every_status_and_compute_units() {
+    let stats = compute_account_stats(&group_by_account(&fixture()));
+    let alice = &stats[&account_id("alice")];
+
+    assert_eq!(alice.pending, 2);
+    assert_eq!(alice.simulated, 2);
+    assert_eq!(alice.rejected, 1);
+    assert_eq!(alice.tx_count(), 5);
+    // Simulated 상태의 compute unit만 합산: 120 + 80
+    assert_eq!(alice.total_compute_units, 200);
+}
+
+#[test]
+fn fee_min_max_mean() {
+    let stats = compute_account_stats(&group_by_account(&fixture()));
+    let alice = &stats[&account_id("alice")];
+
+    assert_eq!(alice.min_fee, Some(500));
+    assert_eq!(alice.max_fee, Some(3000));
+    // (1000 + 3000 + 2000 + 500 + 1500) / 5 = 1600
+    assert_eq!(alice.mean_fee(), Some(1600));
+
+    assert_eq!(AccountStats::default().mean_fee(), None);
+}
+
+#[test]
+fn oldest_pending_age_uses_only_pending_transactions() {
+    let stats = compute_account_stats(&group_by_account(&fixture()));
+
+    // alice의 Pending은 a1(1_000), a5(500) → 가장 오래된 것은 500
+    assert_eq!(stats[&account_id("alice")].oldest_pending_at_ms, Some(500));
+    assert_eq!(
+        stats[&account_id("alice")].oldest_pending_age_ms(10_000),
+        Some(9_500)
+    );
+
+    // carol은 Pending이 없음
+    assert_eq!(
+        stats[&account_id("carol")].oldest_pending_age_ms(10_000),
+        None
+    );
+
+    // 시계가 뒤로 가도 음수(underflow) 대신 0
+    assert_eq!(stats[&account_id("bob")].oldest_pending_age_ms(0), Some(0));
+}
+
+#[test]
+fn totals_do_not_overflow_on_busy_accounts() {
+    let txs = vec![
+        tx("x1", "whale", u64::MAX, u32::MAX, TxStatus::Pending, 0),
+        tx("x2", "whale", u64::MAX, u32::MAX, TxStatus::Pending, 0),
+        tx(
+            "x3",
+            "whale",
+            u64::MAX,
+            u32::MAX,
+            TxStatus::Simulated {
+                compute_units: u64::MAX,
+            },
+            0,
+        ),
+        tx(
+            "x4",
+            "whale",
+            u64::MAX,
+            u32::MAX,
+            TxStatus::Simulated {
+                compute_units: u64::MAX,
+            },
+            0,
+        ),
+    ];
+
+    let stats = compute_account_stats(&group_by_account(&txs));
+    let whale = &stats[&account_id("whale")];
+    assert_eq!(whale.total_fee, u64::MAX as u128 * 4);
+    assert_eq!(whale.total_bytes, u32::MAX as u64 * 4);
+    assert_eq!(whale.total_compute_units, u64::MAX as u128 * 2);
+    assert_eq!(whale.mean_fee(), Some(u64::MAX));
+}
+
+#[test]
+fn top_accounts_sorts_by_requested_metric() {
+    let stats = compute_account_stats(&group_by_account(&fixture()));
+    let names = |metric: StatsMetric, n: usize| -> Vec<String> {
+        top_accounts(&stats, metric, n)
+            .into_iter()
+            .map(|(account, _)| label(account.as_bytes()))
+            .collect()
+    };
+
+    assert_eq!(names(StatsMetric::TotalFee, 2), vec!["bob", "alice"]);
+    assert_eq!(names(StatsMetric::TxCount, 1), vec!["alice"]);
+    assert_eq!(
+        names(StatsMetric::ComputeUnits, 3),
+        vec!["carol", "alice", "bob"]
+    );
+    assert_eq!(names(StatsMetric::MaxFee, 3), vec!["bob", "alice", "carol"]);
+    assert_eq!(
+        names(StatsMetric::OldestPendingAge { now_ms: 10_000 }, 2),
+        vec!["alice", "bob"]
+    );
+
+    // n이 계정 수보다 크면 전체를 돌려줌
+    assert_eq!(names(StatsMetric::Pending, 10).len(), 3);
+}
+
+#[test]
+fn top_accounts_breaks_ties_by_account_id() {
+    let txs = vec![
+        tx("1", "zed", 100, 1, TxStatus::Pending, 0),
+        tx("2", "amy", 100, 1, TxStatus::Pending, 0),
+        tx("3", "kim", 100, 1, TxStatus::Pending, 0),
+    ];
+    let stats = compute_account_stats(&group_by_account(&txs));
+
+    let ranked: Vec<String> = top_accounts(&stats, StatsMetric::TotalFee, 3)
+        .into_iter()
+        .map(|(account, _)| label(account.as_bytes()))
+        .collect();
+    assert_eq!(ranked, vec!["amy", "kim", "zed"]);
+}
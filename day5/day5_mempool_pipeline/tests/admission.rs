@@ -0,0 +1,236 @@
+// AdmissionController가 계정별 트랜잭션 수/바이트 한도와 전역 바이트 예산을 지키고,
+// 예산 초과 시 가장 많이 쓰는 계정의 저수수료 트랜잭션부터 축출하며,
+// 그 판단을 타입이 있는 결과로 돌려주는지 검증합니다.
+
+mod common;
+
+use common::{account_id, label, tx_id};
+use day5_mempool_pipeline::*;
+
+fn tx(id: &str, account: &str, nonce: u64, fee: u64, size: u32) -> PendingTx {
+    PendingTx {
+        id: tx_id(id),
+        account: account_id(account),
+        fee_micro_lamports: fee,
+        payload_size: size,
+        status: TxStatus::Pending,
+        received_at_ms: 0,
+        nonce,
+    }
+}
+
+fn controller(max_txs: usize, max_account_bytes: u64, max_total_bytes: u64) -> AdmissionController {
+    AdmissionController::new(AdmissionLimits {
+        max_txs_per_account: max_txs,
+        max_bytes_per_account: max_account_bytes,
+        max_total_bytes,
+    })
+}
+
+#[test]
+fn accepts_within_all_limits() {
+    let gate = controller(10, 1_000, 10_000);
+    let mut pool = Mempool::new();
+
+    assert_eq!(
+        gate.admit(&mut pool, tx("a0", "alice", 0, 100, 200)),
+        AdmissionDecision::Accepted
+    );
+    assert_eq!(pool.len(), 1);
+}
+
+#[test]
+fn enforces_per_account_transaction_quota() {
+    let gate = controller(2, 1_000, 10_000);
+    let mut pool = Mempool::new();
+    gate.admit(&mut pool, tx("a0", "alice", 0, 100, 10));
+    gate.admit(&mut pool, tx("a1", "alice", 1, 100, 10));
+
+    assert_eq!(
+        gate.admit(&mut pool, tx("a2", "alice", 2, 100, 10)),
+        AdmissionDecision::Rejected(AdmissionRejection::AccountTxLimit {
+            account: account_id("alice"),
+            limit: 2,
+        })
+    );
+    // 다른 계정은 영향을 받지 않음
+    assert!(
+        gate.admit(&mut pool, tx("b0", "bob", 0, 100, 10))
+            .is_accepted()
+    );
+    assert_eq!(pool.len(), 3);
+}
+
+#[test]
+fn enforces_per_account_byte_quota() {
+    let gate = controller(10, 500, 10_000);
+    let mut pool = Mempool::new();
+    gate.admit(&mut pool, tx("a0", "alice", 0, 100, 400));
+
+    assert_eq!(
+        gate.admit(&mut pool, tx("a1", "alice", 1, 100, 101)),
+        AdmissionDecision::Rejected(AdmissionRejection::AccountByteLimit {
+            account: account_id("alice"),
+            requested: 501,
+            limit: 500,
+        })
+    );
+    assert!(
+        gate.admit(&mut pool, tx("a1", "alice", 1, 100, 100))
+            .is_accepted()
+    );
+}
+
+#[test]
+fn rejects_payload_larger_than_the_whole_budget() {
+    let gate = controller(10, 10_000, 1_000);
+    let pool = Mempool::new();
+
+    assert_eq!(
+        gate.check(&pool, &tx("big", "alice", 0, 100, 1_001)),
+        AdmissionDecision::Rejected(AdmissionRejection::PayloadTooLarge {
+            size: 1_001,
+            budget: 1_000,
+        })
+    );
+}
+
+#[test]
+fn over_budget_evicts_highest_nonce_from_heaviest_account() {
+    let gate = controller(10, 1_000, 1_000);
+    let mut pool = Mempool::new();
+    // alice가 600바이트로 가장 많이 씀. 중간 nonce(a1)의 수수료가 가장 낮음
+    gate.admit(&mut pool, tx("a0", "alice", 0, 500, 200));
+    gate.admit(&mut pool, tx("a1", "alice", 1, 50, 200));
+    gate.admit(&mut pool, tx("a2", "alice", 2, 300, 200));
+    gate.admit(&mut pool, tx("b0", "bob", 0, 10, 300));
+    assert_eq!(pool.index().total_bytes(), 900);
+
+    // 300바이트가 더 들어오면 200바이트 초과 → 수수료와 관계없이 alice의 맨 뒤 nonce(a2) 하나만 축출
+    let decision = gate.admit(&mut pool, tx("c0", "carol", 0, 1, 300));
+    assert_eq!(
+        decision,
+        AdmissionDecision::AcceptedWithEvictions {
+            evicted: vec![tx_id("a2")],
+        }
+    );
+    assert!(pool.get(&tx_id("a2")).is_none());
+    assert!(pool.get(&tx_id("a1")).is_some());
+    assert!(pool.get(&tx_id("c0")).is_some());
+    // bob의 수수료가 가장 낮아도 가장 많이 쓰는 계정이 아니므로 살아남음
+    assert!(pool.get(&tx_id("b0")).is_some());
+    assert_eq!(pool.index().total_bytes(), 1_000);
+    // 맨 뒤부터 뺐으므로 nonce 빈틈이 생기지 않음
+    assert!(pool.gaps(&account_id("alice")).is_empty());
+}
+
+#[test]
+fn eviction_moves_to_next_heaviest_account_as_usage_shifts() {
+    let gate = controller(10, 1_000, 800);
+    let mut pool = Mempool::new();
+    gate.admit(&mut pool, tx("a0", "alice", 0, 100, 200));
+    gate.admit(&mut pool, tx("a1", "alice", 1, 150, 200));
+    gate.admit(&mut pool, tx("b0", "bob", 0, 50, 350));
+
+    // 총 750 + 340 = 1090 → 290 초과
+    // 1회차: alice(400)가 가장 무거움 → 맨 뒤 nonce a1(200) 축출 → 890
+    // 2회차: 이제 bob(350)이 가장 무거움 → b0(350) 축출 → 540
+    let decision = gate.check(&pool, &tx("c0", "carol", 0, 1_000, 340));
+    assert_eq!(
+        decision,
+        AdmissionDecision::AcceptedWithEvictions {
+            evicted: vec![tx_id("a1"), tx_id("b0")],
+        }
+    );
+
+    // 새 트랜잭션의 계정이 가장 무거워지고 그 안에서 nonce가 가장 크면 스스로 밀려나므로 거절
+    let decision = gate.check(&pool, &tx("c0", "carol", 0, 1_000, 500));
+    assert_eq!(
+        decision,
+        AdmissionDecision::Rejected(AdmissionRejection::BudgetExhausted)
+    );
+    // check는 풀을 바꾸지 않음
+    assert_eq!(pool.len(), 3);
+}
+
+#[test]
+fn heaviest_account_ties_break_by_account_name() {
+    let gate = controller(10, 1_000, 1_000);
+    let mut pool = Mempool::new();
+    gate.admit(&mut pool, tx("b0", "bob", 0, 100, 400));
+    gate.admit(&mut pool, tx("a0", "alice", 0, 900, 400));
+
+    // alice와 bob 모두 400 → 이름이 앞선 alice의 트랜잭션이 수수료와 무관하게 먼저 축출됨
+    assert_eq!(
+        gate.check(&pool, &tx("c0", "carol", 0, 1, 300)),
+        AdmissionDecision::AcceptedWithEvictions {
+            evicted: vec![tx_id("a0")],
+        }
+    );
+}
+
+#[test]
+fn incoming_transaction_with_highest_nonce_is_rejected_within_its_account() {
+    let gate = controller(10, 1_000, 500);
+    let mut pool = Mempool::new();
+    gate.admit(&mut pool, tx("a1", "alice", 1, 100, 250));
+    gate.admit(&mut pool, tx("a2", "alice", 2, 100, 250));
+
+    // 같은 계정에서 새 트랜잭션의 nonce가 가장 크면 수수료가 높아도 새 트랜잭션이 거절됨
+    assert_eq!(
+        gate.admit(&mut pool, tx("a3", "alice", 3, 1_000, 100)),
+        AdmissionDecision::Rejected(AdmissionRejection::BudgetExhausted)
+    );
+    // 더 낮은 nonce면 기존의 맨 뒤 nonce 트랜잭션을 밀어냄
+    assert_eq!(
+        gate.admit(&mut pool, tx("a0", "alice", 0, 1, 100)),
+        AdmissionDecision::AcceptedWithEvictions {
+            evicted: vec![tx_id("a2")],
+        }
+    );
+    assert!(pool.gaps(&account_id("alice")).is_empty());
+}
+
+#[test]
+fn pool_rule_violations_surface_as_rejections() {
+    let gate = controller(10, 1_000, 1_000);
+    let mut pool = Mempool::new();
+    gate.admit(&mut pool, tx("a0", "alice", 0, 100, 10));
+
+    assert_eq!(
+        gate.admit(&mut pool, tx("a0", "alice", 1, 100, 10)),
+        AdmissionDecision::Rejected(AdmissionRejection::Mempool(MempoolError::DuplicateId {
+            id: tx_id("a0"),
+        }))
+    );
+}
+
+#[test]
+fn decisions_can_be_inspected_like_filter_transactions() {
+    let gate = controller(1, 1_000, 1_000);
+    let mut pool = Mempool::new();
+    gate.admit(&mut pool, tx("a0", "alice", 0, 100, 10));
+
+    let incoming = vec![
+        tx("a1", "alice", 1, 100, 10),
+        tx("b0", "bob", 0, 100, 10),
+        tx("c0", "carol", 0, 100, 2_000),
+    ];
+
+    let decisions = gate.check_all(&pool, &incoming);
+    let rejected: Vec<String> = decisions
+        .iter()
+        .filter(|(_, decision)| !decision.is_accepted())
+        .map(|(tx, _)| label(tx.id.as_bytes()))
+        .collect();
+    assert_eq!(rejected, vec!["a1", "c0"]);
+    assert!(matches!(
+        decisions[2].1,
+        AdmissionDecision::Rejected(AdmissionRejection::PayloadTooLarge { .. })
+    ));
+
+    // 같은 판단을 MempoolFilter로도 쓸 수 있음
+    let allowed = filter_transactions(&incoming, &gate.filter(&pool));
+    assert_eq!(allowed.len(), 1);
+    assert_eq!(allowed[0].id, tx_id("b0"));
+}
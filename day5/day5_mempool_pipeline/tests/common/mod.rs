@@ -0,0 +1,30 @@
+// 여러 통합 테스트가 함께 쓰는 id 헬퍼입니다.
+// 사람이 읽기 쉬운 라벨("alice", "tx1")을 32바이트 앞쪽에 그대로 복사하고 나머지를 0으로 채우므로,
+// 라벨의 사전순과 id의 바이트 순서가 같아 정렬 기대값을 라벨로 적을 수 있습니다.
+#![allow(dead_code)]
+
+use day5_mempool_pipeline::{AccountId, TxId};
+
+fn label_bytes(label: &str) -> [u8; 32] {
+    assert!(
+        label.len() <= 32,
+        "test label {label:?} is longer than 32 bytes"
+    );
+    let mut bytes = [0u8; 32];
+    bytes[..label.len()].copy_from_slice(label.as_bytes());
+    bytes
+}
+
+pub fn tx_id(label: &str) -> TxId {
+    TxId::new(label_bytes(label))
+}
+
+pub fn account_id(label: &str) -> AccountId {
+    AccountId::new(label_bytes(label))
+}
+
+// tx_id/account_id의 역변환. 실패 메시지와 비교를 라벨로 읽기 위해 씁니다.
+pub fn label(bytes: &[u8; 32]) -> String {
+    let end = bytes.iter().rposition(|&b| b != 0).map_or(0, |i| i + 1);
+    String::from_utf8_lossy(&bytes[..end]).into_owned()
+}
@@ -0,0 +1,107 @@
+// TxId/AccountId가 base58과 hex 표기를 모두 왕복(round-trip)하고,
+// 잘못된 문자열을 타입이 있는 오류로 거절하며, Copy 맵 키로 쓰일 수 있는지 검증합니다.
+
+use std::collections::{BTreeMap, HashSet};
+
+use day5_mempool_pipeline::*;
+
+fn sample_bytes() -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = i as u8 * 7 + 1;
+    }
+    bytes
+}
+
+#[test]
+fn round_trips_through_base58_and_hex() {
+    let id = TxId::new(sample_bytes());
+
+    let base58 = id.to_base58();
+    let hex = id.to_hex();
+    assert_eq!(hex.len(), 64);
+    assert_eq!(TxId::from_base58(&base58).unwrap(), id);
+    assert_eq!(TxId::from_hex(&hex).unwrap(), id);
+
+    // FromStr은 두 표기를 모두 받음
+    assert_eq!(base58.parse::<TxId>().unwrap(), id);
+    assert_eq!(hex.parse::<TxId>().unwrap(), id);
+    assert_eq!(hex.to_uppercase().parse::<TxId>().unwrap(), id);
+}
+
+#[test]
+fn displays_as_base58() {
+    // 32바이트 0은 base58에서 '1'이 32개
+    let zero = AccountId::new([0u8; 32]);
+    assert_eq!(zero.to_string(), "1".repeat(32));
+    assert_eq!(
+        format!("{zero:?}"),
+        format!("AccountId({})", "1".repeat(32))
+    );
+
+    let id = TxId::new(sample_bytes());
+    assert_eq!(id.to_string(), id.to_base58());
+}
+
+#[test]
+fn rejects_malformed_strings() {
+    // base58 알파벳에 없는 문자(0, O, I, l)
+    assert!(matches!(
+        "0OIl".parse::<TxId>(),
+        Err(IdParseError::InvalidBase58(_))
+    ));
+    // 올바른 base58이지만 32바이트가 아님
+    assert_eq!(
+        "2g".parse::<AccountId>(),
+        Err(IdParseError::InvalidLength { got: 1 })
+    );
+    // 64글자지만 16진수가 아니면 base58로 읽고, 32바이트보다 길어서 거절
+    assert!(matches!(
+        "g".repeat(64).parse::<TxId>(),
+        Err(IdParseError::InvalidLength { .. })
+    ));
+    assert!(matches!(
+        TxId::from_hex(&"zz".repeat(32)),
+        Err(IdParseError::InvalidHex(_))
+    ));
+    assert_eq!(
+        TxId::from_hex("abcd"),
+        Err(IdParseError::InvalidHexLength { got: 4 })
+    );
+    // 홀수 길이도 잘라 내지 않고 글자 수 그대로 알려 줌
+    assert_eq!(
+        TxId::from_hex(&"a".repeat(63)),
+        Err(IdParseError::InvalidHexLength { got: 63 })
+    );
+}
+
+#[test]
+fn ids_are_copy_map_keys() {
+    let alice = AccountId::new([1u8; 32]);
+    let bob = AccountId::new([2u8; 32]);
+
+    let mut fees: BTreeMap<AccountId, u64> = BTreeMap::new();
+    // Copy라서 clone 없이 키로 여러 번 씀
+    *fees.entry(bob).or_default() += 10;
+    *fees.entry(alice).or_default() += 5;
+    *fees.entry(bob).or_default() += 1;
+
+    assert_eq!(fees.keys().copied().collect::<Vec<_>>(), vec![alice, bob]);
+    assert_eq!(fees[&bob], 11);
+
+    let seen: HashSet<TxId> = [TxId::new([3u8; 32]), TxId::new([3u8; 32])].into();
+    assert_eq!(seen.len(), 1);
+}
+
+#[test]
+fn serializes_as_base58_strings() {
+    let id = TxId::new(sample_bytes());
+    let json = serde_json::to_string(&id).unwrap();
+    assert_eq!(json, format!("\"{}\"", id.to_base58()));
+    assert_eq!(serde_json::from_str::<TxId>(&json).unwrap(), id);
+
+    // hex 문자열도 읽을 수 있음
+    let from_hex: TxId = serde_json::from_str(&format!("\"{}\"", id.to_hex())).unwrap();
+    assert_eq!(from_hex, id);
+    assert!(serde_json::from_str::<AccountId>("\"not-an-id\"").is_err());
+}
@@ -1,47 +1,60 @@
 // 멤풀 파이프라인의 핵심 기능들을 검증하는 통합 테스트
 // 임계값 필터링, 트랜잭션 필터링, 계정별 그룹화, 통계 계산 기능을 종합적으로 테스트
 
+mod common;
+
+use common::{account_id, tx_id};
 use day5_mempool_pipeline::*;
 
 // 테스트용 Fixture Data 생성 함수
 fn create_test_transactions() -> Vec<PendingTx> {
     vec![
         PendingTx {
-            id: "tx1".to_string(),
-            account: "account1".to_string(),
+            id: tx_id("tx1"),
+            account: account_id("account1"),
             fee_micro_lamports: 1000,
             payload_size: 500,
             status: TxStatus::Pending,
+            received_at_ms: 100,
+            nonce: 0,
         },
         PendingTx {
-            id: "tx2".to_string(),
-            account: "account1".to_string(),
+            id: tx_id("tx2"),
+            account: account_id("account1"),
             fee_micro_lamports: 2000,
             payload_size: 300,
             status: TxStatus::Simulated { compute_units: 100 },
+            received_at_ms: 200,
+            nonce: 1,
         },
         PendingTx {
-            id: "tx3".to_string(),
-            account: "account2".to_string(),
+            id: tx_id("tx3"),
+            account: account_id("account2"),
             fee_micro_lamports: 500, // 낮은 수수료
             payload_size: 800,       // 큰 페이로드
             status: TxStatus::Pending,
+            received_at_ms: 300,
+            nonce: 0,
         },
         PendingTx {
-            id: "tx4".to_string(),
-            account: "account2".to_string(),
+            id: tx_id("tx4"),
+            account: account_id("account2"),
             fee_micro_lamports: 1500,
             payload_size: 200,
             status: TxStatus::Rejected {
                 reason: "insufficient funds".to_string(),
             },
+            received_at_ms: 400,
+            nonce: 1,
         },
         PendingTx {
-            id: "tx5".to_string(),
-            account: "account3".to_string(),
+            id: tx_id("tx5"),
+            account: account_id("account3"),
             fee_micro_lamports: 3000,
             payload_size: 100,
             status: TxStatus::Pending,
+            received_at_ms: 500,
+            nonce: 0,
         },
     ]
 }
@@ -64,12 +77,12 @@ fn test_threshold_filter_all_conditions() {
     // 예상 결과: tx1, tx2, tx5만 통과 (tx3은 수수료 낮음, tx4는 거절됨)
     assert_eq!(filtered.len(), 3);
 
-    let ids: Vec<&String> = filtered.iter().map(|tx| &tx.id).collect();
-    assert!(ids.contains(&&"tx1".to_string()));
-    assert!(ids.contains(&&"tx2".to_string()));
-    assert!(ids.contains(&&"tx5".to_string()));
-    assert!(!ids.contains(&&"tx3".to_string())); // 수수료 낮음
-    assert!(!ids.contains(&&"tx4".to_string())); // 거절됨
+    let ids: Vec<&TxId> = filtered.iter().map(|tx| &tx.id).collect();
+    assert!(ids.contains(&&tx_id("tx1")));
+    assert!(ids.contains(&&tx_id("tx2")));
+    assert!(ids.contains(&&tx_id("tx5")));
+    assert!(!ids.contains(&&tx_id("tx3"))); // 수수료 낮음
+    assert!(!ids.contains(&&tx_id("tx4"))); // 거절됨
 }
 
 #[test]
@@ -90,12 +103,12 @@ fn test_threshold_filter_without_rejecting_simulation_failures() {
     // 예상 결과: tx1, tx2, tx4, tx5 통과 (tx4도 허용됨)
     assert_eq!(filtered.len(), 4);
 
-    let ids: Vec<&String> = filtered.iter().map(|tx| &tx.id).collect();
-    assert!(ids.contains(&&"tx1".to_string()));
-    assert!(ids.contains(&&"tx2".to_string()));
-    assert!(ids.contains(&&"tx4".to_string())); // 이제 허용됨
-    assert!(ids.contains(&&"tx5".to_string()));
-    assert!(!ids.contains(&&"tx3".to_string())); // 여전히 수수료 낮음
+    let ids: Vec<&TxId> = filtered.iter().map(|tx| &tx.id).collect();
+    assert!(ids.contains(&&tx_id("tx1")));
+    assert!(ids.contains(&&tx_id("tx2")));
+    assert!(ids.contains(&&tx_id("tx4"))); // 이제 허용됨
+    assert!(ids.contains(&&tx_id("tx5")));
+    assert!(!ids.contains(&&tx_id("tx3"))); // 여전히 수수료 낮음
 }
 
 #[test]
@@ -156,23 +169,23 @@ fn test_group_by_account() {
     assert_eq!(grouped.len(), 3); // account1, account2, account3
 
     // account1: tx1, tx2
-    let account1_txs = grouped.get("account1").unwrap();
+    let account1_txs = grouped.get(&account_id("account1")).unwrap();
     assert_eq!(account1_txs.len(), 2);
-    let account1_ids: Vec<&String> = account1_txs.iter().map(|tx| &tx.id).collect();
-    assert!(account1_ids.contains(&&"tx1".to_string()));
-    assert!(account1_ids.contains(&&"tx2".to_string()));
+    let account1_ids: Vec<&TxId> = account1_txs.iter().map(|tx| &tx.id).collect();
+    assert!(account1_ids.contains(&&tx_id("tx1")));
+    assert!(account1_ids.contains(&&tx_id("tx2")));
 
     // account2: tx3, tx4
-    let account2_txs = grouped.get("account2").unwrap();
+    let account2_txs = grouped.get(&account_id("account2")).unwrap();
     assert_eq!(account2_txs.len(), 2);
-    let account2_ids: Vec<&String> = account2_txs.iter().map(|tx| &tx.id).collect();
-    assert!(account2_ids.contains(&&"tx3".to_string()));
-    assert!(account2_ids.contains(&&"tx4".to_string()));
+    let account2_ids: Vec<&TxId> = account2_txs.iter().map(|tx| &tx.id).collect();
+    assert!(account2_ids.contains(&&tx_id("tx3")));
+    assert!(account2_ids.contains(&&tx_id("tx4")));
 
     // account3: tx5
-    let account3_txs = grouped.get("account3").unwrap();
+    let account3_txs = grouped.get(&account_id("account3")).unwrap();
     assert_eq!(account3_txs.len(), 1);
-    assert_eq!(account3_txs[0].id, "tx5");
+    assert_eq!(account3_txs[0].id, tx_id("tx5"));
 }
 
 #[test]
@@ -184,19 +197,19 @@ fn test_compute_account_stats() {
     let stats = compute_account_stats(&grouped);
 
     // account1 통계 확인
-    let account1_stats = stats.get("account1").unwrap();
+    let account1_stats = stats.get(&account_id("account1")).unwrap();
     assert_eq!(account1_stats.total_fee, 3000); // 1000 + 2000
     assert_eq!(account1_stats.total_bytes, 800); // 500 + 300
     assert_eq!(account1_stats.pending, 1); // tx1만 Pending
 
     // account2 통계 확인
-    let account2_stats = stats.get("account2").unwrap();
+    let account2_stats = stats.get(&account_id("account2")).unwrap();
     assert_eq!(account2_stats.total_fee, 2000); // 500 + 1500
     assert_eq!(account2_stats.total_bytes, 1000); // 800 + 200
     assert_eq!(account2_stats.pending, 1); // tx3만 Pending
 
     // account3 통계 확인
-    let account3_stats = stats.get("account3").unwrap();
+    let account3_stats = stats.get(&account_id("account3")).unwrap();
     assert_eq!(account3_stats.total_fee, 3000); // 3000
     assert_eq!(account3_stats.total_bytes, 100); // 100
     assert_eq!(account3_stats.pending, 1); // tx5만 Pending
@@ -208,41 +221,49 @@ fn test_compute_account_stats_pending_count() {
 
     let txs = vec![
         PendingTx {
-            id: "tx1".to_string(),
-            account: "test_account".to_string(),
+            id: tx_id("tx1"),
+            account: account_id("test_account"),
             fee_micro_lamports: 1000,
             payload_size: 100,
             status: TxStatus::Pending,
+            received_at_ms: 600,
+            nonce: 0,
         },
         PendingTx {
-            id: "tx2".to_string(),
-            account: "test_account".to_string(),
+            id: tx_id("tx2"),
+            account: account_id("test_account"),
             fee_micro_lamports: 2000,
             payload_size: 200,
             status: TxStatus::Simulated { compute_units: 50 },
+            received_at_ms: 700,
+            nonce: 1,
         },
         PendingTx {
-            id: "tx3".to_string(),
-            account: "test_account".to_string(),
+            id: tx_id("tx3"),
+            account: account_id("test_account"),
             fee_micro_lamports: 3000,
             payload_size: 300,
             status: TxStatus::Rejected {
                 reason: "error".to_string(),
             },
+            received_at_ms: 800,
+            nonce: 2,
         },
         PendingTx {
-            id: "tx4".to_string(),
-            account: "test_account".to_string(),
+            id: tx_id("tx4"),
+            account: account_id("test_account"),
             fee_micro_lamports: 4000,
             payload_size: 400,
             status: TxStatus::Pending,
+            received_at_ms: 900,
+            nonce: 3,
         },
     ];
 
     let grouped = group_by_account(&txs);
     let stats = compute_account_stats(&grouped);
 
-    let account_stats = stats.get("test_account").unwrap();
+    let account_stats = stats.get(&account_id("test_account")).unwrap();
     assert_eq!(account_stats.pending, 2); // tx1, tx4만 Pending
     assert_eq!(account_stats.total_fee, 10000); // 모든 수수료 합계
     assert_eq!(account_stats.total_bytes, 1000); // 모든 바이트 합계
@@ -0,0 +1,192 @@
+// Mempool이 계정별 트랜잭션을 nonce 기준 ready/future 대기열로 나누고,
+// 빈틈이 메워지면 future를 승격하며, 계정마다 비어 있는 nonce 구간을 보고하는지 검증합니다.
+
+mod common;
+
+use common::{account_id, label, tx_id};
+use day5_mempool_pipeline::*;
+
+fn tx(id: &str, account: &str, nonce: u64) -> PendingTx {
+    PendingTx {
+        id: tx_id(id),
+        account: account_id(account),
+        fee_micro_lamports: 1000,
+        payload_size: 100,
+        status: TxStatus::Pending,
+        received_at_ms: 0,
+        nonce,
+    }
+}
+
+fn ids(txs: Vec<&PendingTx>) -> Vec<String> {
+    txs.into_iter().map(|tx| label(tx.id.as_bytes())).collect()
+}
+
+#[test]
+fn contiguous_nonces_go_to_ready_and_gapped_ones_to_future() {
+    let mut pool = Mempool::new();
+
+    assert_eq!(pool.insert(tx("a0", "alice", 0)), Ok(QueuePlacement::Ready));
+    assert_eq!(pool.insert(tx("a1", "alice", 1)), Ok(QueuePlacement::Ready));
+    assert_eq!(
+        pool.insert(tx("a3", "alice", 3)),
+        Ok(QueuePlacement::Future)
+    );
+    assert_eq!(
+        pool.insert(tx("a6", "alice", 6)),
+        Ok(QueuePlacement::Future)
+    );
+
+    assert_eq!(ids(pool.ready(&account_id("alice"))), vec!["a0", "a1"]);
+    assert_eq!(ids(pool.future(&account_id("alice"))), vec!["a3", "a6"]);
+    assert_eq!(pool.gaps(&account_id("alice")), vec![2..3, 4..6]);
+    assert_eq!(pool.len(), 4);
+}
+
+#[test]
+fn filling_a_gap_promotes_waiting_transactions() {
+    let mut pool = Mempool::new();
+    pool.insert(tx("a0", "alice", 0)).unwrap();
+    pool.insert(tx("a2", "alice", 2)).unwrap();
+    pool.insert(tx("a3", "alice", 3)).unwrap();
+    pool.insert(tx("a5", "alice", 5)).unwrap();
+
+    // nonce 1이 들어오면 2, 3까지 승격되고 5는 여전히 4를 기다림
+    assert_eq!(pool.insert(tx("a1", "alice", 1)), Ok(QueuePlacement::Ready));
+    assert_eq!(
+        ids(pool.ready(&account_id("alice"))),
+        vec!["a0", "a1", "a2", "a3"]
+    );
+    assert_eq!(ids(pool.future(&account_id("alice"))), vec!["a5"]);
+    assert_eq!(pool.gaps(&account_id("alice")), vec![4..5]);
+
+    pool.insert(tx("a4", "alice", 4)).unwrap();
+    assert_eq!(pool.future(&account_id("alice")).len(), 0);
+    assert!(pool.gaps(&account_id("alice")).is_empty());
+}
+
+#[test]
+fn removing_a_ready_transaction_demotes_later_ones() {
+    let mut pool = Mempool::new();
+    for nonce in 0..4 {
+        pool.insert(tx(&format!("a{nonce}"), "alice", nonce))
+            .unwrap();
+    }
+
+    let removed = pool.remove(&tx_id("a1")).unwrap();
+    assert_eq!(removed.nonce, 1);
+    assert_eq!(ids(pool.ready(&account_id("alice"))), vec!["a0"]);
+    assert_eq!(ids(pool.future(&account_id("alice"))), vec!["a2", "a3"]);
+    assert_eq!(pool.gaps(&account_id("alice")), vec![1..2]);
+    // 통계도 함께 갱신됨
+    assert_eq!(
+        pool.index()
+            .account_stats(&account_id("alice"))
+            .unwrap()
+            .pending,
+        3
+    );
+}
+
+#[test]
+fn account_nonce_from_chain_drops_executed_and_promotes() {
+    let mut pool = Mempool::new();
+    pool.set_account_nonce(&account_id("alice"), 5);
+
+    assert_eq!(
+        pool.insert(tx("a6", "alice", 6)),
+        Ok(QueuePlacement::Future)
+    );
+    assert_eq!(pool.insert(tx("a5", "alice", 5)), Ok(QueuePlacement::Ready));
+    assert_eq!(ids(pool.ready(&account_id("alice"))), vec!["a5", "a6"]);
+
+    // 블록에서 nonce 5가 실행되면 체인 nonce는 6이 되고 a5는 풀에서 빠짐
+    let dropped = pool.set_account_nonce(&account_id("alice"), 6);
+    assert_eq!(ids(dropped.iter().collect()), vec!["a5"]);
+    assert_eq!(ids(pool.ready(&account_id("alice"))), vec!["a6"]);
+    assert_eq!(pool.next_nonce(&account_id("alice")), Some(6));
+    assert!(pool.get(&tx_id("a5")).is_none());
+}
+
+#[test]
+fn lowering_account_nonce_turns_ready_into_future() {
+    let mut pool = Mempool::new();
+    pool.set_account_nonce(&account_id("alice"), 3);
+    pool.insert(tx("a3", "alice", 3)).unwrap();
+    pool.insert(tx("a4", "alice", 4)).unwrap();
+
+    // reorg로 nonce 2가 되돌아오면, 2가 다시 들어올 때까지 3, 4는 실행할 수 없음
+    assert!(pool.set_account_nonce(&account_id("alice"), 2).is_empty());
+    assert!(pool.ready(&account_id("alice")).is_empty());
+    assert_eq!(pool.gaps(&account_id("alice")), vec![2..3]);
+}
+
+#[test]
+fn rejects_stale_and_duplicate_nonces() {
+    let mut pool = Mempool::new();
+    pool.set_account_nonce(&account_id("alice"), 2);
+
+    assert_eq!(
+        pool.insert(tx("old", "alice", 1)),
+        Err(MempoolError::NonceTooLow {
+            account: account_id("alice"),
+            expected: 2,
+            got: 1,
+        })
+    );
+
+    pool.insert(tx("a2", "alice", 2)).unwrap();
+    assert_eq!(
+        pool.insert(tx("a2-again", "alice", 2)),
+        Err(MempoolError::DuplicateNonce {
+            account: account_id("alice"),
+            nonce: 2,
+        })
+    );
+    assert_eq!(
+        pool.insert(tx("a2", "alice", 3)),
+        Err(MempoolError::DuplicateId { id: tx_id("a2") })
+    );
+    // 마지막 nonce는 다음 nonce가 없어 대기열 계산이 넘치므로 받지 않음
+    assert_eq!(
+        pool.insert(tx("last", "bob", u64::MAX)),
+        Err(MempoolError::NonceOutOfRange {
+            account: account_id("bob"),
+            nonce: u64::MAX,
+        })
+    );
+    pool.insert(tx("b-max", "bob", u64::MAX - 1)).unwrap();
+    assert_eq!(pool.gaps(&account_id("bob")), vec![0..u64::MAX - 1]);
+    assert_eq!(pool.len(), 2);
+}
+
+#[test]
+fn gaps_are_reported_per_account() {
+    let mut pool = Mempool::new();
+    pool.insert(tx("a0", "alice", 0)).unwrap();
+    pool.insert(tx("a2", "alice", 2)).unwrap();
+    pool.insert(tx("b0", "bob", 0)).unwrap();
+    pool.insert(tx("c3", "carol", 3)).unwrap();
+
+    let gaps = pool.gaps_by_account();
+    assert_eq!(gaps.len(), 2);
+    assert_eq!(gaps[&account_id("alice")], vec![1..2]);
+    assert_eq!(gaps[&account_id("carol")], vec![0..3]);
+    assert!(!gaps.contains_key(&account_id("bob")));
+}
+
+#[test]
+fn group_by_account_orders_each_account_by_nonce() {
+    let txs = vec![
+        tx("a2", "alice", 2),
+        tx("a0", "alice", 0),
+        tx("a1", "alice", 1),
+    ];
+    let grouped = group_by_account(&txs);
+
+    let nonces: Vec<u64> = grouped[&account_id("alice")]
+        .iter()
+        .map(|tx| tx.nonce)
+        .collect();
+    assert_eq!(nonces, vec![0, 1, 2]);
+}
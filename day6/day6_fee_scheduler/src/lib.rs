@@ -1,6 +1,10 @@
-use std::collections::BinaryHeap;
+use std::collections::{BinaryHeap, HashMap};
 use std::cmp::Ordering;
 
+// 멤풀 크레이트와 같은 32바이트 트랜잭션 id를 씁니다.
+pub use day5_mempool_pipeline::TxId;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TxClass {
     // 높은 수수료 트랜잭션을 먼저 꺼내는 큐를 구현하기 위해 존재합니다.
     // High priority transactions for urgent processing
@@ -14,10 +18,13 @@ pub enum TxClass {
 }
 
 // 트랜잭션 정보를 담기 위한 구조체
+#[derive(Debug, Clone)]
 pub struct MempoolEntry {
-    pub id: String,
+    pub id: TxId,
     pub fee_micro_lamports: u64,
     pub compute_units: u32,
+    // 직렬화된 트랜잭션 크기(Byte 단위). 슬롯 플래너가 블록 바이트 제한을 검사할 때 씁니다.
+    pub payload_size: u32,
     pub class: TxClass
 }
 
@@ -30,7 +37,12 @@ pub enum SchedulerError {
 }
 
 pub struct PriorityScheduler {
-    heap: BinaryHeap<ScheduledTx>,
+    // 힙 항목마다 push 순번을 붙입니다. remove는 힙을 건드리지 않고 queued에서만 지우고,
+    // pop이 꺼낸 항목의 순번이 queued와 다르면 이미 빠진 항목으로 보고 버립니다. (lazy deletion)
+    heap: BinaryHeap<(ScheduledTx, u64)>,
+    // 큐에 살아 있는 엔트리: id → (push 순번, 엔트리)
+    queued: HashMap<TxId, (u64, MempoolEntry)>,
+    next_seq: u64,
 }
 
 // `ScheduledTx`에 `Ord`, `PartialOrd`, `Eq`, `PartialEq`를 구현해 `score`가 높은 항목이 먼저 나오도록 하세요.
@@ -70,11 +82,17 @@ impl PartialOrd for ScheduledTx {
     }
 }    
 
+impl Default for PriorityScheduler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl PriorityScheduler {
     // 새로운 스케줄러 인스턴스를 생성합니다.
     // Creates a new priority scheduler instance.
     pub fn new() -> Self {
-        Self { heap: BinaryHeap::new() }
+        Self { heap: BinaryHeap::new(), queued: HashMap::new(), next_seq: 0 }
     }
     pub fn push(&mut self, entry: MempoolEntry) -> Result<(), SchedulerError> {
         // - `fee_micro_lamports == 0`이면 `SchedulerError::FeeTooLow`를 반환합니다.
@@ -87,28 +105,56 @@ impl PriorityScheduler {
         }
         // - `score`는 `fee_micro_lamports as u128 * 1_000 + (200_000 - compute_units as u128)`로 계산합니다.
         let score = entry.fee_micro_lamports as u128 * 1_000 + (200_000 - entry.compute_units as u128);
+        // 같은 id가 이미 큐에 있으면 새 엔트리로 바꿉니다. 예전 힙 항목은 pop할 때 걸러집니다.
+        let seq = self.next_seq;
+        self.next_seq += 1;
+        self.queued.insert(entry.id, (seq, entry.clone()));
         let scheduled_tx = ScheduledTx {
             entry,
             score,
         };
-        self.heap.push(scheduled_tx);
+        self.heap.push((scheduled_tx, seq));
+        self.compact();
         Ok(())
     }
     // 가장 높은 우선순위의 엔트리를 꺼냅니다.
     // Pops and returns the highest-priority entry.
     pub fn pop(&mut self) -> Option<MempoolEntry> {
-        self.heap.pop().map(|s| s.entry)
+        while let Some((s, seq)) = self.heap.pop() {
+            if self.queued.get(&s.entry.id).is_some_and(|(live, _)| *live == seq) {
+                self.queued.remove(&s.entry.id);
+                return Some(s.entry);
+            }
+        }
+        None
+    }
+
+    // id가 같은 엔트리를 큐에서 뺍니다. 블록에 포함된 트랜잭션을 정리할 때 씁니다.
+    // 힙은 그대로 두고 queued에서만 지우므로 O(1)입니다.
+    // Removes the entry with the given id, if queued.
+    pub fn remove(&mut self, id: &TxId) -> Option<MempoolEntry> {
+        let (_, entry) = self.queued.remove(id)?;
+        self.compact();
+        Some(entry)
     }
 
     // 현재 큐에 담긴 엔트리 수를 반환합니다.
     // Returns the number of entries in the queue.
     pub fn len(&self) -> usize {
-        self.heap.len()
+        self.queued.len()
     }
 
     // 큐가 비었는지 여부를 반환합니다.
     // Returns whether the queue is empty.
     pub fn is_empty(&self) -> bool {
-        self.heap.is_empty()
+        self.queued.is_empty()
+    }
+
+    // 빠진 항목이 살아 있는 항목보다 너무 많이 쌓이면 힙에서 한 번에 걸러 냅니다.
+    fn compact(&mut self) {
+        if self.heap.len() > 2 * self.queued.len() + 16 {
+            let queued = &self.queued;
+            self.heap.retain(|(s, seq)| queued.get(&s.entry.id).is_some_and(|(live, _)| live == seq));
+        }
     }
 }
\ No newline at end of file
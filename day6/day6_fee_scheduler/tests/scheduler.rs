@@ -3,13 +3,21 @@
 // - 우선순위(점수) 및 동점 시 ID 역순 정렬
 // - 1,000개 랜덤 유사 데이터로 빠르게 넣고 빼기(작은 벤치마크)
 
-use day6_fee_scheduler::{MempoolEntry, PriorityScheduler, SchedulerError, TxClass};
+use day6_fee_scheduler::{MempoolEntry, PriorityScheduler, SchedulerError, TxClass, TxId};
+
+// 라벨을 32바이트 앞쪽에 복사해 id를 만듭니다. 라벨의 사전순이 id의 바이트 순서와 같습니다.
+fn tx_id(label: &str) -> TxId {
+    let mut bytes = [0u8; 32];
+    bytes[..label.len()].copy_from_slice(label.as_bytes());
+    TxId::new(bytes)
+}
 
 fn make_entry(id: &str, fee: u64, cu: u32, class: TxClass) -> MempoolEntry {
     MempoolEntry {
-        id: id.to_string(),
+        id: tx_id(id),
         fee_micro_lamports: fee,
         compute_units: cu,
+        payload_size: 200,
         class,
     }
 }
@@ -47,14 +55,57 @@ fn pop_orders_by_score_then_id_desc() {
 
     // 가장 먼저 e3(pop)
     let first = sched.pop().unwrap();
-    assert_eq!(first.id, "mid");
+    assert_eq!(first.id, tx_id("mid"));
 
     // 그 다음 동점 둘: id 역순 -> "xyz" 먼저, 그 다음 "abc"
     let second = sched.pop().unwrap();
-    assert_eq!(second.id, "xyz");
+    assert_eq!(second.id, tx_id("xyz"));
     let third = sched.pop().unwrap();
-    assert_eq!(third.id, "abc");
+    assert_eq!(third.id, tx_id("abc"));
+
+    assert!(sched.is_empty());
+}
+
+#[test]
+fn remove_takes_entry_out_and_keeps_order() {
+    let mut sched = PriorityScheduler::new();
+    sched.push(make_entry("a", 30, 10_000, TxClass::Standard)).unwrap();
+    sched.push(make_entry("b", 20, 10_000, TxClass::Standard)).unwrap();
+    sched.push(make_entry("c", 10, 10_000, TxClass::Standard)).unwrap();
+
+    // 가운데 점수의 b를 빼도 나머지는 점수 순서대로 나옴
+    let removed = sched.remove(&tx_id("b")).unwrap();
+    assert_eq!(removed.id, tx_id("b"));
+    assert!(sched.remove(&tx_id("b")).is_none());
+    assert_eq!(sched.len(), 2);
+    assert_eq!(sched.pop().unwrap().id, tx_id("a"));
+    assert_eq!(sched.pop().unwrap().id, tx_id("c"));
+}
 
+#[test]
+fn remove_is_lazy_and_repush_replaces_entry() {
+    let mut sched = PriorityScheduler::new();
+    for i in 0..100u32 {
+        sched.push(make_entry(&format!("id_{:03}", i), 10, 10_000 + i, TxClass::Standard)).unwrap();
+    }
+
+    // 짝수 id를 모두 빼면 홀수 id만 점수 순서대로 남음
+    for i in (0..100u32).step_by(2) {
+        assert!(sched.remove(&tx_id(&format!("id_{:03}", i))).is_some());
+    }
+    assert_eq!(sched.len(), 50);
+
+    // 뺐던 id를 다른 점수로 다시 넣으면 새 엔트리만 나오고, 이미 있는 id를 다시 넣으면 엔트리가 바뀜
+    sched.push(make_entry("id_000", 99, 10_000, TxClass::Standard)).unwrap();
+    sched.push(make_entry("id_001", 1, 10_000, TxClass::Standard)).unwrap();
+    assert_eq!(sched.len(), 51);
+
+    let popped: Vec<MempoolEntry> = std::iter::from_fn(|| sched.pop()).collect();
+    assert_eq!(popped.len(), 51);
+    assert_eq!(popped[0].id, tx_id("id_000"));
+    assert_eq!(popped[0].fee_micro_lamports, 99);
+    assert_eq!(popped[1].id, tx_id("id_003"));
+    assert_eq!(popped[50].id, tx_id("id_001"));
     assert!(sched.is_empty());
 }
 
@@ -0,0 +1,327 @@
+// Day 6의 PriorityScheduler가 점수 순으로 내보내는 트랜잭션을 받아, 한 슬롯(블록)에 담을 묶음을 고릅니다.
+// Packs a slot from PriorityScheduler output under compute-unit, byte and transaction-count limits.
+pub use day6_fee_scheduler::{MempoolEntry, PriorityScheduler};
+
+// 배낭 DP의 compute unit 구간 수 상한
+pub const MAX_KNAPSACK_BUCKETS: usize = 1 << 16;
+// 배낭 DP 선택 표(항목 수 × 구간 수)의 칸 수 상한. 칸 하나가 1바이트라 약 16MB입니다.
+pub const MAX_KNAPSACK_CELLS: usize = 1 << 24;
+
+// 이 구조체는 블록 하나가 받아들일 수 있는 한도를 담습니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockConstraint {
+    pub max_compute_units: u32,
+    pub max_bytes: u32,
+    pub max_transactions: usize,
+}
+
+// 이 구조체는 아직 남아 있는 용량(compute unit, 바이트, 트랜잭션 수)을 담습니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Capacity {
+    pub compute_units: u32,
+    pub bytes: u32,
+    pub transactions: usize,
+}
+
+// 항목을 담지 못한 이유. 여러 한도를 동시에 넘으면 compute unit, 바이트, 개수 순으로 먼저 걸린 것을 알려 줍니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SkipReason {
+    ComputeUnits,
+    Bytes,
+    TransactionCount,
+}
+
+// 이 구조체는 담지 못한 항목과 그 이유를 담습니다. 항목은 버리지 않고 돌려주므로 다음 슬롯에 다시 넣을 수 있습니다.
+#[derive(Debug, Clone)]
+pub struct SkippedEntry {
+    pub entry: MempoolEntry,
+    pub reason: SkipReason,
+}
+
+// 이 구조체는 실제로 블록에 담긴 항목과 그 합계를 담습니다.
+#[derive(Debug, Clone, Default)]
+pub struct PlannedBundle {
+    pub entries: Vec<MempoolEntry>,
+    pub total_compute_units: u32,
+    pub total_bytes: u32,
+    pub total_fee_micro_lamports: u64,
+}
+
+// 어떤 방식으로 묶음을 고를지.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PackingMode {
+    // 점수 순으로 꺼내면서 담을 수 있으면 담고, 아니면 건너뜁니다.
+    Greedy,
+    // 수수료 합이 가장 커지도록 compute unit 기준 0/1 배낭 문제를 풉니다.
+    // compute unit은 cu_granularity 단위로 올림해서 계산하므로, 단위가 클수록 빠르지만 덜 빽빽하게 담깁니다.
+    // DP 표는 항목 수 × 구간 수 크기라서, 구간은 최대 MAX_KNAPSACK_BUCKETS개, 표는 최대 MAX_KNAPSACK_CELLS칸으로 제한합니다.
+    // 한도를 넘는 작은 cu_granularity는 max_compute_units와 항목 수에 맞춰 자동으로 커집니다.
+    Knapsack { cu_granularity: u32 },
+}
+
+// 이 구조체는 슬롯 계획의 결과(담긴 묶음, 남은 용량, 건너뛴 항목)를 담습니다.
+#[derive(Debug, Clone)]
+pub struct SlotPlan {
+    pub bundle: PlannedBundle,
+    pub remaining: Capacity,
+    pub skipped: Vec<SkippedEntry>,
+}
+
+pub struct SlotPlanner {
+    constraint: BlockConstraint,
+    current_bundle: PlannedBundle,
+    remaining: Capacity,
+    skipped: Vec<SkippedEntry>,
+}
+
+impl Capacity {
+    // 빈 블록의 용량은 한도 그대로입니다.
+    pub fn of(constraint: &BlockConstraint) -> Self {
+        Self {
+            compute_units: constraint.max_compute_units,
+            bytes: constraint.max_bytes,
+            transactions: constraint.max_transactions,
+        }
+    }
+
+    // 항목을 담을 수 있는지 확인만 합니다.
+    pub fn admits(&self, entry: &MempoolEntry) -> Result<(), SkipReason> {
+        if entry.compute_units > self.compute_units {
+            return Err(SkipReason::ComputeUnits);
+        }
+        if entry.payload_size > self.bytes {
+            return Err(SkipReason::Bytes);
+        }
+        if self.transactions == 0 {
+            return Err(SkipReason::TransactionCount);
+        }
+        Ok(())
+    }
+
+    // admits를 통과한 항목만큼 용량을 줄입니다.
+    fn take(&mut self, entry: &MempoolEntry) {
+        self.compute_units -= entry.compute_units;
+        self.bytes -= entry.payload_size;
+        self.transactions -= 1;
+    }
+}
+
+impl PlannedBundle {
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    // 한도에서 현재 사용량을 뺀 값. 다른 한도로 물어봐도 음수가 되지 않도록 saturating_sub를 씁니다.
+    pub fn remaining_capacity(&self, constraint: &BlockConstraint) -> Capacity {
+        Capacity {
+            compute_units: constraint
+                .max_compute_units
+                .saturating_sub(self.total_compute_units),
+            bytes: constraint.max_bytes.saturating_sub(self.total_bytes),
+            transactions: constraint
+                .max_transactions
+                .saturating_sub(self.entries.len()),
+        }
+    }
+
+    fn push(&mut self, entry: MempoolEntry) {
+        self.total_compute_units += entry.compute_units;
+        self.total_bytes += entry.payload_size;
+        self.total_fee_micro_lamports = self
+            .total_fee_micro_lamports
+            .saturating_add(entry.fee_micro_lamports);
+        self.entries.push(entry);
+    }
+}
+
+impl SlotPlanner {
+    // 계획은 항상 빈 블록에서 시작하므로, 생성자는 한도만 저장하고 번들은 비워 둡니다.
+    pub fn new(constraint: BlockConstraint) -> Self {
+        Self {
+            constraint,
+            current_bundle: PlannedBundle::default(),
+            remaining: Capacity::of(&constraint),
+            skipped: Vec::new(),
+        }
+    }
+
+    pub fn constraint(&self) -> &BlockConstraint {
+        &self.constraint
+    }
+
+    pub fn remaining(&self) -> Capacity {
+        self.remaining
+    }
+
+    // 한도 안이면 번들에 담고 true를 돌려줍니다. 넘으면 건너뛴 목록에 이유와 함께 남기고 false를 돌려줍니다.
+    pub fn try_add(&mut self, entry: MempoolEntry) -> bool {
+        match self.remaining.admits(&entry) {
+            Ok(()) => {
+                self.remaining.take(&entry);
+                self.current_bundle.push(entry);
+                true
+            }
+            Err(reason) => {
+                self.skipped.push(SkippedEntry { entry, reason });
+                false
+            }
+        }
+    }
+
+    pub fn finalize(self) -> SlotPlan {
+        SlotPlan {
+            bundle: self.current_bundle,
+            remaining: self.remaining,
+            skipped: self.skipped,
+        }
+    }
+
+    // 스케줄러를 pop으로 비우면서 슬롯 하나를 계획합니다. 담기지 않은 항목은 SlotPlan::skipped로 돌아옵니다.
+    // 번들 안의 항목은 어느 방식이든 스케줄러 점수 순서를 유지합니다.
+    pub fn plan(
+        constraint: BlockConstraint,
+        mode: PackingMode,
+        scheduler: &mut PriorityScheduler,
+    ) -> SlotPlan {
+        let mut planner = Self::new(constraint);
+        match mode {
+            PackingMode::Greedy => {
+                while let Some(entry) = scheduler.pop() {
+                    planner.try_add(entry);
+                }
+            }
+            PackingMode::Knapsack { cu_granularity } => {
+                let mut entries = Vec::with_capacity(scheduler.len());
+                while let Some(entry) = scheduler.pop() {
+                    entries.push(entry);
+                }
+                let mut picks = knapsack_picks(&entries, &constraint, cu_granularity);
+                // 올림 계산 때문에 배낭 해가 그리디보다 못할 수 있으니, 그럴 때는 그리디 선택을 씁니다.
+                let greedy = greedy_picks(&entries, &constraint);
+                if picked_fee(&entries, &greedy) > picked_fee(&entries, &picks) {
+                    picks = greedy;
+                }
+                fill_picks(&entries, &constraint, &mut picks);
+
+                // 고른 항목을 pop 순서 그대로 먼저 담고, 나머지는 최종 남은 용량 기준의 이유와 함께 건너뜁니다.
+                let (chosen, rest): (Vec<_>, Vec<_>) = entries
+                    .into_iter()
+                    .zip(picks)
+                    .partition(|&(_, picked)| picked);
+                for (entry, _) in chosen {
+                    planner.try_add(entry);
+                }
+                for (entry, _) in rest {
+                    planner.try_add(entry);
+                }
+            }
+        }
+        planner.finalize()
+    }
+}
+
+// 고른 항목을 담고 남은 용량에 들어가는 항목이 있으면 점수 순으로 마저 고릅니다.
+fn fill_picks(entries: &[MempoolEntry], constraint: &BlockConstraint, picks: &mut [bool]) {
+    let mut remaining = Capacity::of(constraint);
+    for (entry, _) in entries
+        .iter()
+        .zip(picks.iter())
+        .filter(|&(_, &picked)| picked)
+    {
+        remaining.take(entry);
+    }
+    for (entry, picked) in entries.iter().zip(picks.iter_mut()) {
+        if !*picked && remaining.admits(entry).is_ok() {
+            remaining.take(entry);
+            *picked = true;
+        }
+    }
+}
+
+fn greedy_picks(entries: &[MempoolEntry], constraint: &BlockConstraint) -> Vec<bool> {
+    let mut remaining = Capacity::of(constraint);
+    entries
+        .iter()
+        .map(|entry| {
+            let fits = remaining.admits(entry).is_ok();
+            if fits {
+                remaining.take(entry);
+            }
+            fits
+        })
+        .collect()
+}
+
+// compute unit을 무게, 수수료를 가치로 두는 0/1 배낭 DP입니다.
+// 바이트와 개수 한도는 상태마다 (수수료, 바이트, 개수)를 같이 들고 다니며 전이할 때 검사합니다.
+// 따라서 항상 모든 한도를 지키는 해를 내지만, 바이트/개수까지 고려한 최적해를 보장하지는 않습니다.
+fn knapsack_picks(
+    entries: &[MempoolEntry],
+    constraint: &BlockConstraint,
+    cu_granularity: u32,
+) -> Vec<bool> {
+    let granularity =
+        knapsack_granularity(constraint.max_compute_units, entries.len(), cu_granularity);
+    let capacity = (constraint.max_compute_units / granularity) as usize;
+    let weight = |entry: &MempoolEntry| entry.compute_units.div_ceil(granularity) as usize;
+
+    // best[w]: 올림한 compute unit 합이 w 이하인 선택 중 수수료가 가장 큰 것의 (수수료, 바이트, 개수)
+    let mut best = vec![(0u64, 0u32, 0usize); capacity + 1];
+    let mut took = vec![vec![false; capacity + 1]; entries.len()];
+    for (index, entry) in entries.iter().enumerate() {
+        let w = weight(entry);
+        if w > capacity {
+            continue;
+        }
+        // 같은 항목을 두 번 담지 않도록 큰 무게부터 거꾸로 갱신합니다.
+        for cap in (w..=capacity).rev() {
+            let (fee, bytes, count) = best[cap - w];
+            let Some(bytes) = bytes
+                .checked_add(entry.payload_size)
+                .filter(|&bytes| bytes <= constraint.max_bytes)
+            else {
+                continue;
+            };
+            if count >= constraint.max_transactions {
+                continue;
+            }
+            let fee = fee.saturating_add(entry.fee_micro_lamports);
+            if fee > best[cap].0 {
+                best[cap] = (fee, bytes, count + 1);
+                took[index][cap] = true;
+            }
+        }
+    }
+
+    let mut picks = vec![false; entries.len()];
+    let mut cap = capacity;
+    for index in (0..entries.len()).rev() {
+        if took[index][cap] {
+            picks[index] = true;
+            cap -= weight(&entries[index]);
+        }
+    }
+    picks
+}
+
+// 구간 수가 MAX_KNAPSACK_BUCKETS와 MAX_KNAPSACK_CELLS / 항목 수를 넘지 않도록 올림 단위를 키웁니다.
+fn knapsack_granularity(max_compute_units: u32, entries: usize, cu_granularity: u32) -> u32 {
+    let max_buckets = (MAX_KNAPSACK_CELLS / entries.max(1)).clamp(1, MAX_KNAPSACK_BUCKETS);
+    // 구간 수는 max_compute_units / 단위 + 1이므로, 단위를 이만큼 잡으면 구간이 max_buckets개를 넘지 않습니다.
+    let floor = max_compute_units.div_ceil(max_buckets as u32).max(1);
+    cu_granularity.max(floor)
+}
+
+fn picked_fee(entries: &[MempoolEntry], picks: &[bool]) -> u64 {
+    entries
+        .iter()
+        .zip(picks)
+        .filter(|&(_, &picked)| picked)
+        .fold(0u64, |total, (entry, _)| {
+            total.saturating_add(entry.fee_micro_lamports)
+        })
+}
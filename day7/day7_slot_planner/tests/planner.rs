@@ -0,0 +1,225 @@
+// 이 테스트는 슬롯 플래너가 compute unit, 바이트, 개수 한도를 지키면서 스케줄러 점수 순으로 묶음을 고르는지 검증합니다.
+
+use day6_fee_scheduler::{TxClass, TxId};
+use day7_slot_planner::{
+    BlockConstraint, Capacity, MempoolEntry, PackingMode, PriorityScheduler, SkipReason,
+    SlotPlanner,
+};
+
+// 라벨을 32바이트 앞쪽에 복사해 id를 만듭니다.
+fn tx_id(label: &str) -> TxId {
+    let mut bytes = [0u8; 32];
+    bytes[..label.len()].copy_from_slice(label.as_bytes());
+    TxId::new(bytes)
+}
+
+fn entry(label: &str, fee: u64, cu: u32, bytes: u32) -> MempoolEntry {
+    MempoolEntry {
+        id: tx_id(label),
+        fee_micro_lamports: fee,
+        compute_units: cu,
+        payload_size: bytes,
+        class: TxClass::Standard,
+    }
+}
+
+fn scheduler_with(entries: Vec<MempoolEntry>) -> PriorityScheduler {
+    let mut scheduler = PriorityScheduler::new();
+    for entry in entries {
+        scheduler.push(entry).unwrap();
+    }
+    scheduler
+}
+
+fn ids(entries: &[MempoolEntry]) -> Vec<TxId> {
+    entries.iter().map(|entry| entry.id).collect()
+}
+
+#[test]
+fn try_add_rejects_over_limit_and_keeps_bundle() {
+    let constraint = BlockConstraint {
+        max_compute_units: 100,
+        max_bytes: 1_000,
+        max_transactions: 2,
+    };
+    let mut planner = SlotPlanner::new(constraint);
+
+    // 첫 항목은 담기고, compute unit을 넘는 두 번째 항목은 건너뜀
+    assert!(planner.try_add(entry("a", 5, 80, 100)));
+    assert!(!planner.try_add(entry("b", 9, 30, 100)));
+    assert_eq!(
+        planner.remaining(),
+        Capacity {
+            compute_units: 20,
+            bytes: 900,
+            transactions: 1,
+        }
+    );
+
+    let plan = planner.finalize();
+    assert_eq!(ids(&plan.bundle.entries), vec![tx_id("a")]);
+    assert_eq!(plan.bundle.total_compute_units, 80);
+    assert_eq!(plan.skipped.len(), 1);
+    assert_eq!(plan.skipped[0].entry.id, tx_id("b"));
+    assert_eq!(plan.skipped[0].reason, SkipReason::ComputeUnits);
+}
+
+#[test]
+fn greedy_plan_follows_scheduler_order_and_reports_leftovers() {
+    let constraint = BlockConstraint {
+        max_compute_units: 1_000,
+        max_bytes: 500,
+        max_transactions: 2,
+    };
+    let mut scheduler = scheduler_with(vec![
+        entry("low", 1, 100, 100),
+        entry("big", 50, 100, 600),
+        entry("high", 40, 300, 200),
+        entry("mid", 20, 200, 100),
+    ]);
+
+    let plan = SlotPlanner::plan(constraint, PackingMode::Greedy, &mut scheduler);
+
+    // 점수 순: big(바이트 초과) -> high -> mid -> low(개수 초과)
+    assert!(scheduler.is_empty());
+    assert_eq!(ids(&plan.bundle.entries), vec![tx_id("high"), tx_id("mid")]);
+    assert_eq!(plan.bundle.total_fee_micro_lamports, 60);
+    assert_eq!(plan.bundle.total_bytes, 300);
+    assert_eq!(plan.remaining, plan.bundle.remaining_capacity(&constraint));
+    assert_eq!(plan.remaining.compute_units, 500);
+
+    let skipped: Vec<_> = plan
+        .skipped
+        .iter()
+        .map(|skipped| (skipped.entry.id, skipped.reason))
+        .collect();
+    assert_eq!(
+        skipped,
+        vec![
+            (tx_id("big"), SkipReason::Bytes),
+            (tx_id("low"), SkipReason::TransactionCount),
+        ]
+    );
+}
+
+#[test]
+fn knapsack_fills_better_than_greedy() {
+    let constraint = BlockConstraint {
+        max_compute_units: 100_000,
+        max_bytes: 10_000,
+        max_transactions: 10,
+    };
+    let entries = || {
+        vec![
+            entry("whale", 200, 60_000, 100),
+            entry("pair-a", 150, 50_000, 100),
+            entry("pair-b", 150, 50_000, 100),
+        ]
+    };
+
+    // 그리디는 점수가 가장 높은 whale 하나만 담고 나머지는 compute unit이 모자람
+    let greedy = SlotPlanner::plan(
+        constraint,
+        PackingMode::Greedy,
+        &mut scheduler_with(entries()),
+    );
+    assert_eq!(greedy.bundle.total_fee_micro_lamports, 200);
+
+    // 배낭 모드는 두 개를 담아 수수료 합이 더 큼
+    let knapsack = SlotPlanner::plan(
+        constraint,
+        PackingMode::Knapsack {
+            cu_granularity: 1_000,
+        },
+        &mut scheduler_with(entries()),
+    );
+    assert_eq!(knapsack.bundle.total_fee_micro_lamports, 300);
+    assert_eq!(knapsack.bundle.total_compute_units, 100_000);
+    assert_eq!(
+        ids(&knapsack
+            .skipped
+            .iter()
+            .map(|s| s.entry.clone())
+            .collect::<Vec<_>>()),
+        vec![tx_id("whale")]
+    );
+    assert_eq!(knapsack.remaining.compute_units, 0);
+}
+
+#[test]
+fn knapsack_respects_byte_and_count_limits() {
+    let constraint = BlockConstraint {
+        max_compute_units: 100_000,
+        max_bytes: 250,
+        max_transactions: 2,
+    };
+    let entries = || {
+        vec![
+            entry("a", 5, 10_000, 100),
+            entry("b", 5, 10_000, 100),
+            entry("c", 5, 10_000, 100),
+            entry("d", 8, 10_000, 200),
+            entry("e", 3, 5_000, 50),
+        ]
+    };
+
+    let greedy = SlotPlanner::plan(
+        constraint,
+        PackingMode::Greedy,
+        &mut scheduler_with(entries()),
+    );
+    let knapsack = SlotPlanner::plan(
+        constraint,
+        PackingMode::Knapsack {
+            cu_granularity: 1_000,
+        },
+        &mut scheduler_with(entries()),
+    );
+
+    // 배낭 모드도 바이트와 개수 한도를 넘지 않고, 수수료 합은 그리디보다 작지 않음
+    assert!(knapsack.bundle.total_bytes <= constraint.max_bytes);
+    assert!(knapsack.bundle.len() <= constraint.max_transactions);
+    assert!(knapsack.bundle.total_fee_micro_lamports >= greedy.bundle.total_fee_micro_lamports);
+    assert_eq!(knapsack.bundle.len() + knapsack.skipped.len(), 5);
+
+    // 번들 안의 항목은 스케줄러가 내보낸 순서 그대로
+    let mut scheduler = scheduler_with(entries());
+    let popped: Vec<TxId> = std::iter::from_fn(|| scheduler.pop())
+        .map(|entry| entry.id)
+        .collect();
+    let positions: Vec<usize> = knapsack
+        .bundle
+        .entries
+        .iter()
+        .map(|entry| popped.iter().position(|id| *id == entry.id).unwrap())
+        .collect();
+    assert!(positions.is_sorted());
+}
+
+#[test]
+fn knapsack_caps_table_size_for_fine_granularity() {
+    // 큰 compute unit 한도에 단위 1을 줘도 DP 표가 MAX_KNAPSACK_CELLS로 제한되어 금방 끝나야 함
+    let constraint = BlockConstraint {
+        max_compute_units: 48_000_000,
+        max_bytes: u32::MAX,
+        max_transactions: 1_000,
+    };
+    let entries: Vec<MempoolEntry> = (0..1_000u32)
+        .map(|i| {
+            entry(
+                &format!("tx{i}"),
+                1 + u64::from(i % 7),
+                100_000 + i * 37,
+                100,
+            )
+        })
+        .collect();
+    let plan = SlotPlanner::plan(
+        constraint,
+        PackingMode::Knapsack { cu_granularity: 1 },
+        &mut scheduler_with(entries),
+    );
+
+    assert!(plan.bundle.total_compute_units <= constraint.max_compute_units);
+    assert_eq!(plan.bundle.len() + plan.skipped.len(), 1_000);
+}
@@ -1,4 +1,9 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+
+mod maintain;
+
+pub use day5_mempool_pipeline::TxId;
+pub use maintain::{MaintenanceReport, PoolMaintainer};
 
 // BlockHeader는 Reth의 블록 헤더가 담는 핵심 정보를 단순화하여 모방합니다.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -11,6 +16,22 @@ pub struct BlockHeader {
     pub parent_hash: String,
     // 난이도
     pub difficulty: u64,
+    // 블록에 포함된 트랜잭션 id. 멤풀이 어떤 트랜잭션이 처리되었는지 알 때 씁니다.
+    pub transactions: Vec<TxId>,
+}
+
+// canonical 체인이 바뀐 내용을 알려 줍니다. Reth의 CanonStateNotification을 단순화했습니다.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CanonStateNotification {
+    // head 뒤에 블록이 이어 붙었습니다.
+    Commit {
+        new: Vec<BlockHeader>,
+    },
+    // old 블록들이 canonical에서 밀려나고 new 블록들이 그 자리를 차지했습니다. 둘 다 번호 오름차순입니다.
+    Reorg {
+        old: Vec<BlockHeader>,
+        new: Vec<BlockHeader>,
+    },
 }
 
 pub struct HeaderBuffer {
@@ -63,10 +84,17 @@ pub enum HeaderInsertError {
     NumberMismatch { expected: u64, got: u64 },
     // 해시가 중복된 경우
     DuplicateHash { hash: String },
+    // reorg에 빈 브랜치가 들어온 경우
+    EmptyBranch,
+    // 새 브랜치의 total difficulty가 지금 canonical보다 크지 않은 경우
+    NotHeavier { current: u128, candidate: u128 },
 }
 
 impl HeaderBuffer {
-    pub fn try_append(&mut self, header: BlockHeader) -> Result<(), HeaderInsertError> {
+    pub fn try_append(
+        &mut self,
+        header: BlockHeader,
+    ) -> Result<CanonStateNotification, HeaderInsertError> {
         if self.index_by_hash.contains_key(&header.hash) {
             return Err(HeaderInsertError::DuplicateHash {
                 hash: header.hash.clone(),
@@ -89,8 +117,78 @@ impl HeaderBuffer {
         self.total_difficulty = self
             .total_difficulty
             .saturating_add(header.difficulty as u128);
-        self.canonical.push(header);
+        self.canonical.push(header.clone());
 
-        Ok(())
+        Ok(CanonStateNotification::Commit { new: vec![header] })
+    }
+
+    // canonical 중간의 블록에서 갈라진 브랜치로 체인을 갈아 끼웁니다.
+    // branch[0]의 부모가 갈라진 지점이고, 브랜치는 그 뒤로 번호와 부모 해시가 빈틈 없이 이어져야 합니다.
+    // fork choice 규칙대로 total difficulty가 지금보다 클 때만 바꿉니다.
+    pub fn try_reorg(
+        &mut self,
+        branch: Vec<BlockHeader>,
+    ) -> Result<CanonStateNotification, HeaderInsertError> {
+        let first = branch.first().ok_or(HeaderInsertError::EmptyBranch)?;
+        let fork_index = *self.index_by_hash.get(&first.parent_hash).ok_or_else(|| {
+            HeaderInsertError::ParentNotFound {
+                parent_hash: first.parent_hash.clone(),
+            }
+        })?;
+
+        let mut parent = &self.canonical[fork_index];
+        // 기존 체인뿐 아니라 브랜치 안에서도 해시가 겹치면 index_by_hash가 꼬이므로 거절합니다.
+        let mut branch_hashes = HashSet::new();
+        for header in &branch {
+            if header.parent_hash != parent.hash {
+                return Err(HeaderInsertError::ParentNotFound {
+                    parent_hash: header.parent_hash.clone(),
+                });
+            }
+            if header.number != parent.number + 1 {
+                return Err(HeaderInsertError::NumberMismatch {
+                    expected: parent.number + 1,
+                    got: header.number,
+                });
+            }
+            if self.index_by_hash.contains_key(&header.hash)
+                || !branch_hashes.insert(header.hash.as_str())
+            {
+                return Err(HeaderInsertError::DuplicateHash {
+                    hash: header.hash.clone(),
+                });
+            }
+            parent = header;
+        }
+
+        // 갈라진 지점 뒤의 난이도를 빼고 브랜치의 난이도를 더한 값이 새 체인의 total difficulty입니다.
+        let difficulty_sum =
+            |headers: &[BlockHeader]| headers.iter().map(|h| h.difficulty as u128).sum::<u128>();
+        let candidate = self.total_difficulty - difficulty_sum(&self.canonical[fork_index + 1..])
+            + difficulty_sum(&branch);
+        if candidate <= self.total_difficulty {
+            return Err(HeaderInsertError::NotHeavier {
+                current: self.total_difficulty,
+                candidate,
+            });
+        }
+
+        let old = self.canonical.split_off(fork_index + 1);
+        for header in &old {
+            self.index_by_hash.remove(&header.hash);
+        }
+        for header in &branch {
+            self.index_by_hash
+                .insert(header.hash.clone(), self.canonical.len());
+            self.canonical.push(header.clone());
+        }
+        self.total_difficulty = candidate;
+
+        if old.is_empty() {
+            // head에서 갈라졌다면 그냥 이어 붙인 것과 같습니다.
+            Ok(CanonStateNotification::Commit { new: branch })
+        } else {
+            Ok(CanonStateNotification::Reorg { old, new: branch })
+        }
     }
 }
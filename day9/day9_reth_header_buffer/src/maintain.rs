@@ -0,0 +1,174 @@
+use std::collections::{BTreeMap, HashMap, VecDeque};
+
+use day5_mempool_pipeline::{AccountId, Mempool, PendingTx, TxId};
+use day6_fee_scheduler::{MempoolEntry, PriorityScheduler};
+
+use crate::{BlockHeader, CanonStateNotification};
+
+// 풀에서 뺀 트랜잭션 원본과, 그때 스케줄러에서 뺀 엔트리 (스케줄러에 없었다면 None)
+type RemovedTx = (PendingTx, Option<MempoolEntry>);
+
+// 한 번의 canonical 변경으로 풀에서 무엇이 바뀌었는지 알려 줍니다.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MaintenanceReport {
+    // 새 블록에 포함되어 풀과 스케줄러에서 뺀 트랜잭션
+    pub included: Vec<TxId>,
+    // 포함된 트랜잭션보다 nonce가 낮아 더는 실행될 수 없어서 뺀 트랜잭션
+    pub stale: Vec<TxId>,
+    // 밀려난 블록에서 풀로 되돌린 트랜잭션 (새 블록에 다시 포함된 것은 빠집니다)
+    pub reinjected: Vec<TxId>,
+    // 밀려난 블록에 있었지만 원본을 모르거나 풀이 다시 받지 않아 되돌리지 못한 트랜잭션
+    pub dropped: Vec<TxId>,
+    // 풀에는 되돌렸지만 스케줄러가 원래 엔트리를 다시 받지 않은 트랜잭션 (reinjected에도 들어 있습니다)
+    pub unscheduled: Vec<TxId>,
+}
+
+// HeaderBuffer의 CanonStateNotification을 받아 멤풀과 스케줄러를 canonical 체인에 맞춥니다.
+// Reth의 maintain_transaction_pool을 단순화했습니다.
+// 블록 헤더에는 트랜잭션 id만 있으므로, reorg 때 되돌릴 수 있도록 최근 블록 때문에 풀에서 뺀 트랜잭션 원본을 기억해 둡니다.
+// Keeps the mempool and scheduler in sync with canonical head changes and reorgs.
+pub struct PoolMaintainer {
+    // 이보다 깊은 reorg의 트랜잭션은 되돌리지 못합니다.
+    max_reorg_depth: usize,
+    // 기억하고 있는 블록 해시. 오래된 것이 앞에 있습니다.
+    recent_blocks: VecDeque<String>,
+    // 블록 해시 → 그 블록 때문에 풀에서 뺀 트랜잭션 원본과 스케줄러 엔트리 (포함된 것과 stale이 된 것 모두)
+    removed_by_block: HashMap<String, Vec<RemovedTx>>,
+}
+
+impl PoolMaintainer {
+    pub fn new(max_reorg_depth: usize) -> Self {
+        Self {
+            max_reorg_depth,
+            recent_blocks: VecDeque::new(),
+            removed_by_block: HashMap::new(),
+        }
+    }
+
+    pub fn on_canonical_change(
+        &mut self,
+        notification: &CanonStateNotification,
+        mempool: &mut Mempool,
+        scheduler: &mut PriorityScheduler,
+    ) -> MaintenanceReport {
+        let mut report = MaintenanceReport::default();
+        match notification {
+            CanonStateNotification::Commit { new } => {
+                for block in new {
+                    self.apply_block(block, mempool, scheduler, &mut report);
+                }
+            }
+            CanonStateNotification::Reorg { old, new } => {
+                // 먼저 밀려난 블록의 트랜잭션을 모두 되돌린 뒤 새 블록을 적용합니다.
+                // 두 체인에 모두 들어 있는 트랜잭션은 되돌렸다가 곧바로 다시 빠집니다.
+                self.retract_blocks(old, mempool, scheduler, &mut report);
+                for block in new {
+                    self.apply_block(block, mempool, scheduler, &mut report);
+                }
+                let included = &report.included;
+                report.reinjected.retain(|id| !included.contains(id));
+                report.unscheduled.retain(|id| !included.contains(id));
+            }
+        }
+        report
+    }
+
+    // 블록에 포함된 트랜잭션을 풀과 스케줄러에서 빼고, 계정 nonce를 그 뒤로 올립니다.
+    // 그 때문에 stale이 된 트랜잭션도 블록과 함께 기억해 두어야, 블록이 밀려났을 때 같이 되돌릴 수 있습니다.
+    fn apply_block(
+        &mut self,
+        block: &BlockHeader,
+        mempool: &mut Mempool,
+        scheduler: &mut PriorityScheduler,
+        report: &mut MaintenanceReport,
+    ) {
+        let mut removed = Vec::new();
+        for id in &block.transactions {
+            // 풀에서 본 적 없는 트랜잭션은 정리할 것도, 기억할 원본도 없습니다.
+            let Some(tx) = mempool.remove(id) else {
+                continue;
+            };
+            let entry = scheduler.remove(id);
+            report.included.push(*id);
+
+            let next_nonce = tx.nonce + 1;
+            if mempool
+                .next_nonce(&tx.account)
+                .is_none_or(|current| current < next_nonce)
+            {
+                for stale in mempool.set_account_nonce(&tx.account, next_nonce) {
+                    let stale_entry = scheduler.remove(&stale.id);
+                    report.stale.push(stale.id);
+                    removed.push((stale, stale_entry));
+                }
+            }
+            removed.push((tx, entry));
+        }
+        self.remember(block.hash.clone(), removed);
+    }
+
+    // 밀려난 블록의 트랜잭션을 nonce 순서대로 풀과 스케줄러에 되돌립니다.
+    fn retract_blocks(
+        &mut self,
+        old: &[BlockHeader],
+        mempool: &mut Mempool,
+        scheduler: &mut PriorityScheduler,
+        report: &mut MaintenanceReport,
+    ) {
+        let mut by_account: BTreeMap<AccountId, Vec<RemovedTx>> = BTreeMap::new();
+        for block in old {
+            self.recent_blocks.retain(|hash| *hash != block.hash);
+            let known = self
+                .removed_by_block
+                .remove(&block.hash)
+                .unwrap_or_default();
+            for id in &block.transactions {
+                if !known.iter().any(|(tx, _)| tx.id == *id) {
+                    report.dropped.push(*id);
+                }
+            }
+            for removed in known {
+                by_account
+                    .entry(removed.0.account)
+                    .or_default()
+                    .push(removed);
+            }
+        }
+
+        for (account, mut txs) in by_account {
+            txs.sort_by_key(|(tx, _)| tx.nonce);
+            // 체인 nonce를 되돌린 블록 이전으로 내려야 되돌린 트랜잭션이 다시 ready가 됩니다.
+            let lowest = txs[0].0.nonce;
+            if mempool
+                .next_nonce(&account)
+                .is_some_and(|current| current > lowest)
+            {
+                mempool.set_account_nonce(&account, lowest);
+            }
+            for (tx, entry) in txs {
+                let id = tx.id;
+                if mempool.insert(tx).is_err() {
+                    report.dropped.push(id);
+                    continue;
+                }
+                report.reinjected.push(id);
+                // 빼기 전에 스케줄러에 없던 트랜잭션은 되돌린 뒤에도 스케줄러에 올리지 않습니다.
+                if let Some(entry) = entry
+                    && scheduler.push(entry).is_err()
+                {
+                    report.unscheduled.push(id);
+                }
+            }
+        }
+    }
+
+    fn remember(&mut self, hash: String, removed: Vec<RemovedTx>) {
+        self.recent_blocks.push_back(hash.clone());
+        self.removed_by_block.insert(hash, removed);
+        while self.recent_blocks.len() > self.max_reorg_depth {
+            if let Some(oldest) = self.recent_blocks.pop_front() {
+                self.removed_by_block.remove(&oldest);
+            }
+        }
+    }
+}
@@ -1,6 +1,8 @@
 // 이 테스트는 헤더 버퍼가 부모/번호/난이도 규칙을 지키며 상태를 일관되게 유지함을 보장한다.
 
-use day9_reth_header_buffer::{BlockHeader, HeaderBuffer, HeaderInsertError};
+use day9_reth_header_buffer::{
+    BlockHeader, CanonStateNotification, HeaderBuffer, HeaderInsertError,
+};
 
 fn mk_header(number: u64, hash: &str, parent_hash: &str, difficulty: u64) -> BlockHeader {
     BlockHeader {
@@ -8,6 +10,7 @@ fn mk_header(number: u64, hash: &str, parent_hash: &str, difficulty: u64) -> Blo
         hash: hash.to_string(),
         parent_hash: parent_hash.to_string(),
         difficulty,
+        transactions: Vec::new(),
     }
 }
 
@@ -69,3 +72,76 @@ fn number_mismatch_is_rejected_without_state_change() {
     assert_eq!(buf.len(), 1);
     assert_eq!(buf.total_difficulty(), 1);
 }
+
+#[test]
+fn heavier_branch_reorganizes_canonical_chain() {
+    let genesis = mk_header(0, "genesis", "", 1);
+    let mut buf = HeaderBuffer::new(genesis);
+    buf.try_append(mk_header(1, "a1", "genesis", 2)).unwrap();
+    buf.try_append(mk_header(2, "a2", "a1", 2)).unwrap();
+
+    // genesis에서 갈라진 b1(1) + b2(5): TD 1 + 6 = 7 > 5
+    let branch = vec![
+        mk_header(1, "b1", "genesis", 1),
+        mk_header(2, "b2", "b1", 5),
+    ];
+    match buf.try_reorg(branch).unwrap() {
+        CanonStateNotification::Reorg { old, new } => {
+            let hashes = |headers: &[BlockHeader]| {
+                headers.iter().map(|h| h.hash.clone()).collect::<Vec<_>>()
+            };
+            assert_eq!(hashes(&old), vec!["a1", "a2"]);
+            assert_eq!(hashes(&new), vec!["b1", "b2"]);
+        }
+        other => panic!("expected Reorg, got: {:?}", other),
+    }
+    assert_eq!(buf.head().unwrap().hash, "b2");
+    assert_eq!(buf.len(), 3);
+    assert_eq!(buf.total_difficulty(), 7);
+
+    // 밀려난 a2 위로는 더 이상 이어 붙일 수 없음
+    assert!(matches!(
+        buf.try_append(mk_header(3, "a3", "a2", 1)),
+        Err(HeaderInsertError::ParentNotFound { .. })
+    ));
+}
+
+#[test]
+fn lighter_branch_is_rejected_without_state_change() {
+    let genesis = mk_header(0, "genesis", "", 1);
+    let mut buf = HeaderBuffer::new(genesis);
+    buf.try_append(mk_header(1, "a1", "genesis", 4)).unwrap();
+
+    // 같은 TD(5)는 바꾸지 않음
+    match buf.try_reorg(vec![mk_header(1, "b1", "genesis", 4)]) {
+        Err(HeaderInsertError::NotHeavier { current, candidate }) => {
+            assert_eq!((current, candidate), (5, 5));
+        }
+        other => panic!("expected NotHeavier, got: {:?}", other),
+    }
+    assert!(matches!(
+        buf.try_reorg(Vec::new()),
+        Err(HeaderInsertError::EmptyBranch)
+    ));
+    assert_eq!(buf.head().unwrap().hash, "a1");
+    assert_eq!(buf.total_difficulty(), 5);
+}
+
+#[test]
+fn branch_repeating_a_hash_is_rejected_without_state_change() {
+    let genesis = mk_header(0, "genesis", "", 1);
+    let mut buf = HeaderBuffer::new(genesis);
+    buf.try_append(mk_header(1, "a1", "genesis", 1)).unwrap();
+
+    // b2가 b1과 같은 해시를 쓰면 부모 연결은 맞아 보여도 거절
+    let branch = vec![
+        mk_header(1, "b1", "genesis", 5),
+        mk_header(2, "b1", "b1", 5),
+    ];
+    assert!(matches!(
+        buf.try_reorg(branch),
+        Err(HeaderInsertError::DuplicateHash { hash }) if hash == "b1"
+    ));
+    assert_eq!(buf.head().unwrap().hash, "a1");
+    assert_eq!(buf.total_difficulty(), 2);
+}
@@ -0,0 +1,251 @@
+// 이 테스트는 새 head와 reorg에 맞춰 멤풀과 스케줄러가 포함된 트랜잭션을 빼고, 밀려난 트랜잭션을 되돌리는지 검증한다.
+
+use day5_mempool_pipeline::{AccountId, Mempool, PendingTx, TxId, TxStatus};
+use day6_fee_scheduler::{MempoolEntry, PriorityScheduler, TxClass};
+use day9_reth_header_buffer::{BlockHeader, HeaderBuffer, PoolMaintainer};
+
+fn label_bytes(label: &str) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    bytes[..label.len()].copy_from_slice(label.as_bytes());
+    bytes
+}
+
+fn tx_id(label: &str) -> TxId {
+    TxId::new(label_bytes(label))
+}
+
+fn account(label: &str) -> AccountId {
+    AccountId::new(label_bytes(label))
+}
+
+fn pending(id: &str, sender: &str, nonce: u64) -> PendingTx {
+    PendingTx {
+        id: tx_id(id),
+        account: account(sender),
+        fee_micro_lamports: 1_000,
+        payload_size: 200,
+        status: TxStatus::Simulated {
+            compute_units: 10_000,
+        },
+        received_at_ms: 0,
+        nonce,
+    }
+}
+
+fn mk_header(
+    number: u64,
+    hash: &str,
+    parent_hash: &str,
+    difficulty: u64,
+    txs: &[&str],
+) -> BlockHeader {
+    BlockHeader {
+        number,
+        hash: hash.to_string(),
+        parent_hash: parent_hash.to_string(),
+        difficulty,
+        transactions: txs.iter().map(|label| tx_id(label)).collect(),
+    }
+}
+
+// 멤풀과 스케줄러에 같은 트랜잭션을 넣습니다.
+fn pool_with(txs: Vec<PendingTx>) -> (Mempool, PriorityScheduler) {
+    let mut mempool = Mempool::new();
+    let mut scheduler = PriorityScheduler::new();
+    for tx in txs {
+        scheduler
+            .push(MempoolEntry {
+                id: tx.id,
+                fee_micro_lamports: tx.fee_micro_lamports,
+                compute_units: 10_000,
+                payload_size: tx.payload_size,
+                class: TxClass::Standard,
+            })
+            .unwrap();
+        mempool.insert(tx).unwrap();
+    }
+    (mempool, scheduler)
+}
+
+fn scheduled_ids(scheduler: &mut PriorityScheduler) -> Vec<TxId> {
+    let mut ids: Vec<TxId> = std::iter::from_fn(|| scheduler.pop())
+        .map(|e| e.id)
+        .collect();
+    ids.sort();
+    ids
+}
+
+fn ready_ids(mempool: &Mempool, sender: &str) -> Vec<TxId> {
+    mempool
+        .ready(&account(sender))
+        .iter()
+        .map(|tx| tx.id)
+        .collect()
+}
+
+#[test]
+fn new_head_removes_included_and_stale_transactions() {
+    let (mut mempool, mut scheduler) = pool_with(vec![
+        pending("a0", "alice", 0),
+        pending("a1", "alice", 1),
+        pending("a2", "alice", 2),
+        pending("b0", "bob", 0),
+    ]);
+    let mut maintainer = PoolMaintainer::new(16);
+    let mut buf = HeaderBuffer::new(mk_header(0, "genesis", "", 1, &[]));
+
+    // 블록에 alice의 nonce 1만 들어오면 nonce 0(a0)은 더는 실행될 수 없음
+    let notification = buf
+        .try_append(mk_header(1, "h1", "genesis", 1, &["a1"]))
+        .unwrap();
+    let report = maintainer.on_canonical_change(&notification, &mut mempool, &mut scheduler);
+
+    assert_eq!(report.included, vec![tx_id("a1")]);
+    assert_eq!(report.stale, vec![tx_id("a0")]);
+    assert!(report.reinjected.is_empty());
+    assert_eq!(mempool.next_nonce(&account("alice")), Some(2));
+    assert_eq!(ready_ids(&mempool, "alice"), vec![tx_id("a2")]);
+    assert_eq!(mempool.len(), 2);
+    assert_eq!(
+        scheduled_ids(&mut scheduler),
+        vec![tx_id("a2"), tx_id("b0")]
+    );
+}
+
+#[test]
+fn reorg_reinjects_transactions_made_stale_by_retracted_block() {
+    let (mut mempool, mut scheduler) = pool_with(vec![
+        pending("a0", "alice", 0),
+        pending("a1", "alice", 1),
+        pending("a2", "alice", 2),
+    ]);
+    let mut maintainer = PoolMaintainer::new(16);
+    let mut buf = HeaderBuffer::new(mk_header(0, "genesis", "", 1, &[]));
+
+    // h1에 a1만 들어와 a0이 stale로 빠짐
+    let notification = buf
+        .try_append(mk_header(1, "h1", "genesis", 1, &["a1"]))
+        .unwrap();
+    let report = maintainer.on_canonical_change(&notification, &mut mempool, &mut scheduler);
+    assert_eq!(report.stale, vec![tx_id("a0")]);
+
+    // h1이 밀려나면 포함된 a1뿐 아니라 h1 때문에 stale이 된 a0도 돌아와야 함
+    let notification = buf
+        .try_reorg(vec![mk_header(1, "c1", "genesis", 2, &[])])
+        .unwrap();
+    let report = maintainer.on_canonical_change(&notification, &mut mempool, &mut scheduler);
+
+    assert_eq!(report.reinjected, vec![tx_id("a0"), tx_id("a1")]);
+    assert!(report.dropped.is_empty());
+    assert_eq!(mempool.next_nonce(&account("alice")), Some(0));
+    assert_eq!(
+        ready_ids(&mempool, "alice"),
+        vec![tx_id("a0"), tx_id("a1"), tx_id("a2")]
+    );
+    assert_eq!(
+        scheduled_ids(&mut scheduler),
+        vec![tx_id("a0"), tx_id("a1"), tx_id("a2")]
+    );
+}
+
+#[test]
+fn reorg_reinjects_retracted_transactions() {
+    let (mut mempool, mut scheduler) = pool_with(vec![
+        pending("a0", "alice", 0),
+        pending("a1", "alice", 1),
+        pending("a2", "alice", 2),
+        pending("b0", "bob", 0),
+    ]);
+    let mut maintainer = PoolMaintainer::new(16);
+    let mut buf = HeaderBuffer::new(mk_header(0, "genesis", "", 1, &[]));
+
+    // 원래 체인: h1(a0, b0) → h2(a1)
+    for header in [
+        mk_header(1, "h1", "genesis", 1, &["a0", "b0"]),
+        mk_header(2, "h2", "h1", 1, &["a1"]),
+    ] {
+        let notification = buf.try_append(header).unwrap();
+        maintainer.on_canonical_change(&notification, &mut mempool, &mut scheduler);
+    }
+    assert_eq!(mempool.len(), 1);
+    assert_eq!(mempool.next_nonce(&account("alice")), Some(2));
+
+    // genesis에서 갈라진 더 무거운 체인: c1(b0) → c2(비어 있음). b0만 다시 포함됨
+    let notification = buf
+        .try_reorg(vec![
+            mk_header(1, "c1", "genesis", 2, &["b0"]),
+            mk_header(2, "c2", "c1", 2, &[]),
+        ])
+        .unwrap();
+    let report = maintainer.on_canonical_change(&notification, &mut mempool, &mut scheduler);
+
+    // alice의 a0, a1은 풀로 돌아오고, 두 체인에 모두 있는 b0은 다시 빠짐
+    assert_eq!(report.reinjected, vec![tx_id("a0"), tx_id("a1")]);
+    assert_eq!(report.included, vec![tx_id("b0")]);
+    assert!(report.dropped.is_empty());
+    assert_eq!(mempool.next_nonce(&account("alice")), Some(0));
+    assert_eq!(
+        ready_ids(&mempool, "alice"),
+        vec![tx_id("a0"), tx_id("a1"), tx_id("a2")]
+    );
+    assert!(mempool.get(&tx_id("b0")).is_none());
+    assert_eq!(
+        scheduled_ids(&mut scheduler),
+        vec![tx_id("a0"), tx_id("a1"), tx_id("a2")]
+    );
+}
+
+#[test]
+fn reorg_restores_original_scheduler_entries() {
+    // a0은 시뮬레이션 전이라 compute unit을 모르지만, 스케줄러에는 10_000으로 올라가 있음
+    let mut a0 = pending("a0", "alice", 0);
+    a0.status = TxStatus::Pending;
+    let (mut mempool, mut scheduler) = pool_with(vec![a0]);
+    // b0은 풀에만 있고 스케줄러에는 없음
+    mempool.insert(pending("b0", "bob", 0)).unwrap();
+    let mut maintainer = PoolMaintainer::new(16);
+    let mut buf = HeaderBuffer::new(mk_header(0, "genesis", "", 1, &[]));
+
+    let notification = buf
+        .try_append(mk_header(1, "h1", "genesis", 1, &["a0", "b0"]))
+        .unwrap();
+    maintainer.on_canonical_change(&notification, &mut mempool, &mut scheduler);
+    assert!(scheduler.is_empty());
+
+    let notification = buf
+        .try_reorg(vec![mk_header(1, "c1", "genesis", 2, &[])])
+        .unwrap();
+    let report = maintainer.on_canonical_change(&notification, &mut mempool, &mut scheduler);
+
+    // 둘 다 풀로 돌아오지만, 스케줄러에는 원래 엔트리 그대로 a0만 올라감
+    assert_eq!(report.reinjected, vec![tx_id("a0"), tx_id("b0")]);
+    assert!(report.unscheduled.is_empty());
+    let entries: Vec<(TxId, u32)> = std::iter::from_fn(|| scheduler.pop())
+        .map(|entry| (entry.id, entry.compute_units))
+        .collect();
+    assert_eq!(entries, vec![(tx_id("a0"), 10_000)]);
+}
+
+#[test]
+fn reorg_deeper_than_memory_drops_transactions() {
+    let (mut mempool, mut scheduler) = pool_with(vec![pending("a0", "alice", 0)]);
+    // 블록 하나만 기억함
+    let mut maintainer = PoolMaintainer::new(1);
+    let mut buf = HeaderBuffer::new(mk_header(0, "genesis", "", 1, &[]));
+    for header in [
+        mk_header(1, "h1", "genesis", 1, &["a0"]),
+        mk_header(2, "h2", "h1", 1, &[]),
+    ] {
+        let notification = buf.try_append(header).unwrap();
+        maintainer.on_canonical_change(&notification, &mut mempool, &mut scheduler);
+    }
+
+    // h1의 원본은 이미 잊었으므로 a0은 되돌리지 못하고 dropped로 알려 줌
+    let notification = buf
+        .try_reorg(vec![mk_header(1, "c1", "genesis", 5, &[])])
+        .unwrap();
+    let report = maintainer.on_canonical_change(&notification, &mut mempool, &mut scheduler);
+    assert_eq!(report.dropped, vec![tx_id("a0")]);
+    assert!(report.reinjected.is_empty());
+    assert!(mempool.is_empty());
+}